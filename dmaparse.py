@@ -0,0 +1,231 @@
+import xml.etree.ElementTree as ElementTree
+import re
+from pathlib import Path
+
+from natsort import natsorted
+
+db_path = Path(r'C:\Program Files (x86)\STMicroelectronics\STM32Cube\STM32CubeMX\db\mcu')
+FEATURE = 'F0'
+
+ns = '{http://mcd.rou.st.com/modules.php?name=mcu}'
+ip = ns + 'IP'
+
+# DMA request mapping of the controller variants from the reference manual RM0091,
+# request -> [(channel, remap)]
+REQUEST_MAPS = {
+    'DMA1_5': {
+        'ADC': [('DMA1_Channel1', 'Syscfg("ADC_DMA_RMP", false)'), ('DMA1_Channel2', 'Syscfg("ADC_DMA_RMP", true)')],
+        'DAC_CH1': [('DMA1_Channel3', 'Fixed')],
+        'I2C1_RX': [('DMA1_Channel3', 'Fixed')],
+        'I2C1_TX': [('DMA1_Channel2', 'Fixed')],
+        'I2C2_RX': [('DMA1_Channel5', 'Fixed')],
+        'I2C2_TX': [('DMA1_Channel4', 'Fixed')],
+        'SPI1_RX': [('DMA1_Channel2', 'Fixed')],
+        'SPI1_TX': [('DMA1_Channel3', 'Fixed')],
+        'SPI2_RX': [('DMA1_Channel4', 'Fixed')],
+        'SPI2_TX': [('DMA1_Channel5', 'Fixed')],
+        'TIM1_CH1': [('DMA1_Channel2', 'Fixed')],
+        'TIM1_CH2': [('DMA1_Channel3', 'Fixed')],
+        'TIM1_CH3': [('DMA1_Channel5', 'Fixed')],
+        'TIM1_CH4': [('DMA1_Channel4', 'Fixed')],
+        'TIM1_COM': [('DMA1_Channel4', 'Fixed')],
+        'TIM1_TRIG': [('DMA1_Channel4', 'Fixed')],
+        'TIM1_UP': [('DMA1_Channel5', 'Fixed')],
+        'TIM2_CH1': [('DMA1_Channel5', 'Fixed')],
+        'TIM2_CH2': [('DMA1_Channel3', 'Fixed')],
+        'TIM2_CH3': [('DMA1_Channel1', 'Fixed')],
+        'TIM2_CH4': [('DMA1_Channel4', 'Fixed')],
+        'TIM2_UP': [('DMA1_Channel2', 'Fixed')],
+        'TIM3_CH1': [('DMA1_Channel4', 'Fixed')],
+        'TIM3_CH3': [('DMA1_Channel2', 'Fixed')],
+        'TIM3_CH4': [('DMA1_Channel3', 'Fixed')],
+        'TIM3_TRIG': [('DMA1_Channel4', 'Fixed')],
+        'TIM3_UP': [('DMA1_Channel3', 'Fixed')],
+        'TIM6_UP': [('DMA1_Channel3', 'Fixed')],
+        'TIM15_CH1': [('DMA1_Channel5', 'Fixed')],
+        'TIM15_COM': [('DMA1_Channel5', 'Fixed')],
+        'TIM15_TRIG': [('DMA1_Channel5', 'Fixed')],
+        'TIM15_UP': [('DMA1_Channel5', 'Fixed')],
+        'TIM16_CH1': [('DMA1_Channel3', 'Syscfg("TIM16_DMA_RMP", false)'), ('DMA1_Channel4', 'Syscfg("TIM16_DMA_RMP", true)')],
+        'TIM16_UP': [('DMA1_Channel3', 'Syscfg("TIM16_DMA_RMP", false)'), ('DMA1_Channel4', 'Syscfg("TIM16_DMA_RMP", true)')],
+        'TIM17_CH1': [('DMA1_Channel1', 'Syscfg("TIM17_DMA_RMP", false)'), ('DMA1_Channel2', 'Syscfg("TIM17_DMA_RMP", true)')],
+        'TIM17_UP': [('DMA1_Channel1', 'Syscfg("TIM17_DMA_RMP", false)'), ('DMA1_Channel2', 'Syscfg("TIM17_DMA_RMP", true)')],
+        'USART1_RX': [('DMA1_Channel3', 'Syscfg("USART1_RX_DMA_RMP", false)'), ('DMA1_Channel5', 'Syscfg("USART1_RX_DMA_RMP", true)')],
+        'USART1_TX': [('DMA1_Channel2', 'Syscfg("USART1_TX_DMA_RMP", false)'), ('DMA1_Channel4', 'Syscfg("USART1_TX_DMA_RMP", true)')],
+        'USART2_RX': [('DMA1_Channel5', 'Fixed')],
+        'USART2_TX': [('DMA1_Channel4', 'Fixed')],
+    },
+    'DMA1_7': {
+        'ADC': [('DMA1_Channel1', 'Syscfg("ADC_DMA_RMP", false)'), ('DMA1_Channel2', 'Syscfg("ADC_DMA_RMP", true)')],
+        'DAC_CH1': [('DMA1_Channel3', 'Fixed')],
+        'DAC_CH2': [('DMA1_Channel4', 'Fixed')],
+        'I2C1_RX': [('DMA1_Channel3', 'Syscfg("I2C1_DMA_RMP", false)'), ('DMA1_Channel7', 'Syscfg("I2C1_DMA_RMP", true)')],
+        'I2C1_TX': [('DMA1_Channel2', 'Syscfg("I2C1_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("I2C1_DMA_RMP", true)')],
+        'I2C2_RX': [('DMA1_Channel5', 'Fixed')],
+        'I2C2_TX': [('DMA1_Channel4', 'Fixed')],
+        'SPI1_RX': [('DMA1_Channel2', 'Fixed')],
+        'SPI1_TX': [('DMA1_Channel3', 'Fixed')],
+        'SPI2_RX': [('DMA1_Channel4', 'Syscfg("SPI2_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("SPI2_DMA_RMP", true)')],
+        'SPI2_TX': [('DMA1_Channel5', 'Syscfg("SPI2_DMA_RMP", false)'), ('DMA1_Channel7', 'Syscfg("SPI2_DMA_RMP", true)')],
+        'TIM1_CH1': [('DMA1_Channel2', 'Syscfg("TIM1_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("TIM1_DMA_RMP", true)')],
+        'TIM1_CH2': [('DMA1_Channel3', 'Syscfg("TIM1_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("TIM1_DMA_RMP", true)')],
+        'TIM1_CH3': [('DMA1_Channel5', 'Syscfg("TIM1_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("TIM1_DMA_RMP", true)')],
+        'TIM1_CH4': [('DMA1_Channel4', 'Fixed')],
+        'TIM1_COM': [('DMA1_Channel4', 'Fixed')],
+        'TIM1_TRIG': [('DMA1_Channel4', 'Fixed')],
+        'TIM1_UP': [('DMA1_Channel5', 'Fixed')],
+        'TIM2_CH1': [('DMA1_Channel5', 'Fixed')],
+        'TIM2_CH2': [('DMA1_Channel3', 'Syscfg("TIM2_DMA_RMP", false)'), ('DMA1_Channel7', 'Syscfg("TIM2_DMA_RMP", true)')],
+        'TIM2_CH3': [('DMA1_Channel1', 'Fixed')],
+        'TIM2_CH4': [('DMA1_Channel4', 'Syscfg("TIM2_DMA_RMP", false)'), ('DMA1_Channel7', 'Syscfg("TIM2_DMA_RMP", true)')],
+        'TIM2_UP': [('DMA1_Channel2', 'Fixed')],
+        'TIM3_CH1': [('DMA1_Channel4', 'Syscfg("TIM3_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("TIM3_DMA_RMP", true)')],
+        'TIM3_CH3': [('DMA1_Channel2', 'Fixed')],
+        'TIM3_CH4': [('DMA1_Channel3', 'Fixed')],
+        'TIM3_TRIG': [('DMA1_Channel4', 'Syscfg("TIM3_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("TIM3_DMA_RMP", true)')],
+        'TIM3_UP': [('DMA1_Channel3', 'Fixed')],
+        'TIM6_UP': [('DMA1_Channel3', 'Fixed')],
+        'TIM7_UP': [('DMA1_Channel4', 'Fixed')],
+        'TIM15_CH1': [('DMA1_Channel5', 'Fixed')],
+        'TIM15_COM': [('DMA1_Channel5', 'Fixed')],
+        'TIM15_TRIG': [('DMA1_Channel5', 'Fixed')],
+        'TIM15_UP': [('DMA1_Channel5', 'Fixed')],
+        'TIM16_CH1': [('DMA1_Channel3', 'Syscfg("TIM16_DMA_RMP", false)'), ('DMA1_Channel4', 'Syscfg("TIM16_DMA_RMP", true)'), ('DMA1_Channel6', 'Syscfg("TIM16_DMA_RMP2", true)')],
+        'TIM16_UP': [('DMA1_Channel3', 'Syscfg("TIM16_DMA_RMP", false)'), ('DMA1_Channel4', 'Syscfg("TIM16_DMA_RMP", true)'), ('DMA1_Channel6', 'Syscfg("TIM16_DMA_RMP2", true)')],
+        'TIM17_CH1': [('DMA1_Channel1', 'Syscfg("TIM17_DMA_RMP", false)'), ('DMA1_Channel2', 'Syscfg("TIM17_DMA_RMP", true)'), ('DMA1_Channel7', 'Syscfg("TIM17_DMA_RMP2", true)')],
+        'TIM17_UP': [('DMA1_Channel1', 'Syscfg("TIM17_DMA_RMP", false)'), ('DMA1_Channel2', 'Syscfg("TIM17_DMA_RMP", true)'), ('DMA1_Channel7', 'Syscfg("TIM17_DMA_RMP2", true)')],
+        'USART1_RX': [('DMA1_Channel3', 'Syscfg("USART1_RX_DMA_RMP", false)'), ('DMA1_Channel5', 'Syscfg("USART1_RX_DMA_RMP", true)')],
+        'USART1_TX': [('DMA1_Channel2', 'Syscfg("USART1_TX_DMA_RMP", false)'), ('DMA1_Channel4', 'Syscfg("USART1_TX_DMA_RMP", true)')],
+        'USART2_RX': [('DMA1_Channel5', 'Syscfg("USART2_DMA_RMP", false)'), ('DMA1_Channel6', 'Syscfg("USART2_DMA_RMP", true)')],
+        'USART2_TX': [('DMA1_Channel4', 'Syscfg("USART2_DMA_RMP", false)'), ('DMA1_Channel7', 'Syscfg("USART2_DMA_RMP", true)')],
+        'USART3_RX': [('DMA1_Channel3', 'Syscfg("USART3_DMA_RMP", true)'), ('DMA1_Channel6', 'Syscfg("USART3_DMA_RMP", false)')],
+        'USART3_TX': [('DMA1_Channel2', 'Syscfg("USART3_DMA_RMP", true)'), ('DMA1_Channel7', 'Syscfg("USART3_DMA_RMP", false)')],
+    },
+    'CSELR': {
+        'ADC': [('DMA1_Channel1', 'Cselr(1)'), ('DMA1_Channel2', 'Cselr(1)'), ('DMA2_Channel5', 'Cselr(1)')],
+        'DAC_CH1': [('DMA1_Channel3', 'Cselr(1)'), ('DMA2_Channel3', 'Cselr(1)')],
+        'DAC_CH2': [('DMA1_Channel4', 'Cselr(1)'), ('DMA2_Channel4', 'Cselr(1)')],
+        'I2C1_RX': [('DMA1_Channel3', 'Cselr(2)'), ('DMA1_Channel7', 'Cselr(2)')],
+        'I2C1_TX': [('DMA1_Channel2', 'Cselr(2)'), ('DMA1_Channel6', 'Cselr(2)')],
+        'I2C2_RX': [('DMA1_Channel5', 'Cselr(2)'), ('DMA2_Channel2', 'Cselr(2)')],
+        'I2C2_TX': [('DMA1_Channel4', 'Cselr(2)'), ('DMA2_Channel1', 'Cselr(2)')],
+        'SPI1_RX': [('DMA1_Channel2', 'Cselr(3)'), ('DMA2_Channel3', 'Cselr(3)')],
+        'SPI1_TX': [('DMA1_Channel3', 'Cselr(3)'), ('DMA2_Channel4', 'Cselr(3)')],
+        'SPI2_RX': [('DMA1_Channel4', 'Cselr(3)'), ('DMA1_Channel6', 'Cselr(3)')],
+        'SPI2_TX': [('DMA1_Channel5', 'Cselr(3)'), ('DMA1_Channel7', 'Cselr(3)')],
+        'TIM1_CH1': [('DMA1_Channel2', 'Cselr(4)'), ('DMA1_Channel6', 'Cselr(4)')],
+        'TIM1_CH2': [('DMA1_Channel3', 'Cselr(4)'), ('DMA1_Channel6', 'Cselr(4)')],
+        'TIM1_CH3': [('DMA1_Channel5', 'Cselr(4)'), ('DMA1_Channel6', 'Cselr(4)')],
+        'TIM2_CH2': [('DMA1_Channel7', 'Cselr(5)')],
+        'TIM2_CH4': [('DMA1_Channel4', 'Cselr(5)'), ('DMA1_Channel7', 'Cselr(5)')],
+        'TIM3_CH1': [('DMA1_Channel4', 'Cselr(6)'), ('DMA1_Channel6', 'Cselr(6)')],
+        'TIM3_TRIG': [('DMA1_Channel4', 'Cselr(6)'), ('DMA1_Channel6', 'Cselr(6)')],
+        'TIM6_UP': [('DMA1_Channel3', 'Cselr(1)'), ('DMA2_Channel3', 'Cselr(1)')],
+        'TIM7_UP': [('DMA1_Channel4', 'Cselr(1)'), ('DMA2_Channel4', 'Cselr(1)')],
+        'TIM16_CH1': [('DMA1_Channel3', 'Cselr(7)'), ('DMA1_Channel4', 'Cselr(7)'), ('DMA1_Channel6', 'Cselr(7)')],
+        'TIM16_UP': [('DMA1_Channel3', 'Cselr(7)'), ('DMA1_Channel4', 'Cselr(7)'), ('DMA1_Channel6', 'Cselr(7)')],
+        'TIM17_CH1': [('DMA1_Channel1', 'Cselr(7)'), ('DMA1_Channel2', 'Cselr(7)'), ('DMA1_Channel7', 'Cselr(7)')],
+        'TIM17_UP': [('DMA1_Channel1', 'Cselr(7)'), ('DMA1_Channel2', 'Cselr(7)'), ('DMA1_Channel7', 'Cselr(7)')],
+        'USART1_RX': [('DMA1_Channel1', 'Cselr(8)'), ('DMA1_Channel3', 'Cselr(8)'), ('DMA1_Channel5', 'Cselr(8)'), ('DMA1_Channel6', 'Cselr(8)'), ('DMA2_Channel2', 'Cselr(8)'), ('DMA2_Channel3', 'Cselr(8)')],
+        'USART1_TX': [('DMA1_Channel2', 'Cselr(8)'), ('DMA1_Channel4', 'Cselr(8)'), ('DMA1_Channel7', 'Cselr(8)'), ('DMA2_Channel1', 'Cselr(8)'), ('DMA2_Channel4', 'Cselr(8)'), ('DMA2_Channel5', 'Cselr(8)')],
+        'USART2_RX': [('DMA1_Channel1', 'Cselr(9)'), ('DMA1_Channel3', 'Cselr(9)'), ('DMA1_Channel5', 'Cselr(9)'), ('DMA1_Channel6', 'Cselr(9)'), ('DMA2_Channel2', 'Cselr(9)'), ('DMA2_Channel3', 'Cselr(9)')],
+        'USART2_TX': [('DMA1_Channel2', 'Cselr(9)'), ('DMA1_Channel4', 'Cselr(9)'), ('DMA1_Channel7', 'Cselr(9)'), ('DMA2_Channel1', 'Cselr(9)'), ('DMA2_Channel4', 'Cselr(9)'), ('DMA2_Channel5', 'Cselr(9)')],
+        'USART3_RX': [('DMA1_Channel1', 'Cselr(10)'), ('DMA1_Channel3', 'Cselr(10)'), ('DMA1_Channel5', 'Cselr(10)'), ('DMA1_Channel6', 'Cselr(10)'), ('DMA2_Channel2', 'Cselr(10)'), ('DMA2_Channel3', 'Cselr(10)')],
+        'USART3_TX': [('DMA1_Channel2', 'Cselr(10)'), ('DMA1_Channel4', 'Cselr(10)'), ('DMA1_Channel7', 'Cselr(10)'), ('DMA2_Channel1', 'Cselr(10)'), ('DMA2_Channel4', 'Cselr(10)'), ('DMA2_Channel5', 'Cselr(10)')],
+        'USART4_RX': [('DMA1_Channel1', 'Cselr(11)'), ('DMA1_Channel3', 'Cselr(11)'), ('DMA1_Channel5', 'Cselr(11)'), ('DMA1_Channel6', 'Cselr(11)'), ('DMA2_Channel2', 'Cselr(11)'), ('DMA2_Channel3', 'Cselr(11)')],
+        'USART4_TX': [('DMA1_Channel2', 'Cselr(11)'), ('DMA1_Channel4', 'Cselr(11)'), ('DMA1_Channel7', 'Cselr(11)'), ('DMA2_Channel1', 'Cselr(11)'), ('DMA2_Channel4', 'Cselr(11)'), ('DMA2_Channel5', 'Cselr(11)')],
+        'USART5_RX': [('DMA1_Channel1', 'Cselr(12)'), ('DMA1_Channel3', 'Cselr(12)'), ('DMA1_Channel5', 'Cselr(12)'), ('DMA1_Channel6', 'Cselr(12)'), ('DMA2_Channel2', 'Cselr(12)'), ('DMA2_Channel3', 'Cselr(12)')],
+        'USART5_TX': [('DMA1_Channel2', 'Cselr(12)'), ('DMA1_Channel4', 'Cselr(12)'), ('DMA1_Channel7', 'Cselr(12)'), ('DMA2_Channel1', 'Cselr(12)'), ('DMA2_Channel4', 'Cselr(12)'), ('DMA2_Channel5', 'Cselr(12)')],
+        'USART6_RX': [('DMA1_Channel1', 'Cselr(13)'), ('DMA1_Channel3', 'Cselr(13)'), ('DMA1_Channel5', 'Cselr(13)'), ('DMA1_Channel6', 'Cselr(13)'), ('DMA2_Channel2', 'Cselr(13)'), ('DMA2_Channel3', 'Cselr(13)')],
+        'USART6_TX': [('DMA1_Channel2', 'Cselr(13)'), ('DMA1_Channel4', 'Cselr(13)'), ('DMA1_Channel7', 'Cselr(13)'), ('DMA2_Channel1', 'Cselr(13)'), ('DMA2_Channel4', 'Cselr(13)'), ('DMA2_Channel5', 'Cselr(13)')],
+        'USART7_RX': [('DMA1_Channel1', 'Cselr(14)'), ('DMA1_Channel3', 'Cselr(14)'), ('DMA1_Channel5', 'Cselr(14)'), ('DMA1_Channel6', 'Cselr(14)'), ('DMA2_Channel2', 'Cselr(14)'), ('DMA2_Channel3', 'Cselr(14)')],
+        'USART7_TX': [('DMA1_Channel2', 'Cselr(14)'), ('DMA1_Channel4', 'Cselr(14)'), ('DMA1_Channel7', 'Cselr(14)'), ('DMA2_Channel1', 'Cselr(14)'), ('DMA2_Channel4', 'Cselr(14)'), ('DMA2_Channel5', 'Cselr(14)')],
+        'USART8_RX': [('DMA1_Channel1', 'Cselr(15)'), ('DMA1_Channel3', 'Cselr(15)'), ('DMA1_Channel5', 'Cselr(15)'), ('DMA1_Channel6', 'Cselr(15)'), ('DMA2_Channel2', 'Cselr(15)'), ('DMA2_Channel3', 'Cselr(15)')],
+        'USART8_TX': [('DMA1_Channel2', 'Cselr(15)'), ('DMA1_Channel4', 'Cselr(15)'), ('DMA1_Channel7', 'Cselr(15)'), ('DMA2_Channel1', 'Cselr(15)'), ('DMA2_Channel4', 'Cselr(15)'), ('DMA2_Channel5', 'Cselr(15)')],
+    },
+}
+
+# the variant and the channels of a part, first match wins,
+# e.g. the F030xC has the CSELR request selection but only the five channels of DMA1
+DMA1_5 = [f'DMA1_Channel{i}' for i in range(1, 6)]
+DMA1_7 = [f'DMA1_Channel{i}' for i in range(1, 8)]
+DMA2_5 = [f'DMA2_Channel{i}' for i in range(1, 6)]
+
+VARIANTS = [
+    (r'^STM32F09[18]', 'CSELR', DMA1_7 + DMA2_5),
+    (r'^STM32F030.C', 'CSELR', DMA1_5),
+    (r'^STM32F07[128]|^STM32F070.B', 'DMA1_7', DMA1_7),
+    (r'^STM32F0', 'DMA1_5', DMA1_5),
+]
+
+def get_instances():
+    """maps every MCU RefName (Mcu.UserName in the ioc) to the peripheral instances in its MCU xml"""
+    instances = {}
+
+    root = ElementTree.parse(str(db_path / 'families.xml')).getroot()
+
+    for family in root:
+        for subfamily in family:
+            for mcu in subfamily:
+                ref_name = mcu.attrib['RefName']
+                if not ref_name.startswith('STM32' + FEATURE):
+                    continue
+
+                mcu_root = ElementTree.parse(str(db_path / (mcu.attrib['Name'] + '.xml'))).getroot()
+                instances[ref_name] = {i.attrib['InstanceName'] for i in mcu_root.findall(ip)}
+
+    return instances
+
+
+def get_dma_map(ref_name: str, instances: set):
+    """the requests of the peripherals the part has, on the channels it has"""
+    variant, channels = next((v, c) for pattern, v, c in VARIANTS if re.match(pattern, ref_name))
+
+    dma_map = []
+    for request, entries in REQUEST_MAPS[variant].items():
+        # e.g. TIM1 for TIM1_CH1, the ADC and DAC instances can be numbered
+        peripheral = request.split('_')[0]
+        if peripheral not in instances and peripheral + '1' not in instances:
+            continue
+
+        entries = tuple((channel, remap) for channel, remap in entries if channel in channels)
+        if entries:
+            dma_map.append((request, entries))
+
+    return tuple(dma_map)
+
+
+def static_name(ref_name: str):
+    # "STM32F042C4Tx" -> "DMA_STM32F042C4TX"
+    return 'DMA_' + ref_name.upper()
+
+
+def generate(instances: dict):
+    # parts with the same requests share one static
+    statics = {}
+
+    maps_string = 'pub static DMA_MAPS: Map<&str, &DmaMap> = phf_map! {\n'
+    for ref_name, part_instances in natsorted(instances.items()):
+        dma_map = get_dma_map(ref_name, part_instances)
+        if dma_map not in statics:
+            statics[dma_map] = static_name(ref_name)
+        maps_string += f'    "{ref_name}" => &{statics[dma_map]},\n'
+    maps_string += '};\n'
+
+    outstring = ''
+    for dma_map, static in statics.items():
+        outstring += f'\nstatic {static}: DmaMap = phf_map! {{\n'
+        for request, entries in dma_map:
+            entries_string = ', '.join(f'("{channel}", {remap})' for channel, remap in entries)
+            outstring += f'    "{request}" => &[{entries_string}],\n'
+        outstring += '};\n'
+
+    imports = '// DMA request mapping from the reference manual RM0091, generated by dmaparse.py\n' \
+              'use super::{DmaMap, DmaRemap::*};\n' \
+              'use phf::{phf_map, Map};\n\n'
+
+    return imports + maps_string + outstring
+
+
+if __name__ == '__main__':
+    print(generate(get_instances()), end='')
@@ -5,8 +5,23 @@ from natsort import natsorted
 file_path = r'C:\Program Files (x86)\STMicroelectronics\STM32Cube\STM32CubeMX\db\mcu\families.xml'
 FEATURE = 'F0'
 
-
-def get_memory_sizes():
+# families.xml only knows the sizes, the origins come from the reference manual
+# (name in memory.x, xml tag, origin)
+REGIONS = {
+    'F0': [('FLASH', 'Flash', 0x0800_0000), ('RAM', 'Ram', 0x2000_0000)],
+    'F3': [('FLASH', 'Flash', 0x0800_0000), ('RAM', 'Ram', 0x2000_0000), ('CCMRAM', 'CCMRam', 0x1000_0000)],
+    'F4': [('FLASH', 'Flash', 0x0800_0000), ('RAM', 'Ram', 0x2000_0000), ('CCMRAM', 'CCMRam', 0x1000_0000)],
+}
+
+# regions with a fixed size in bytes, e.g. option bytes
+FIXED_REGIONS = {
+    'F0': [('OPTION_BYTES', 0x1FFF_F800, 16)],
+    'F3': [('OPTION_BYTES', 0x1FFF_F800, 16)],
+    'F4': [('BACKUP_SRAM', 0x4002_4000, 4 * 1024), ('OPTION_BYTES', 0x1FFF_C000, 16)],
+}
+
+
+def get_memory_regions():
     mem_info = {}
 
     tree = ElementTree.parse(file_path)
@@ -19,37 +34,50 @@ def get_memory_sizes():
                 if not mcu_name.startswith('STM32' + FEATURE):
                     continue
 
-                rams = mcu.findall('Ram')
-                flashs = mcu.findall('Flash')
-
-                assert len(rams) == 1
-                assert len(flashs) == 1
+                regions = []
+                for name, tag, origin in REGIONS[FEATURE]:
+                    sizes = mcu.findall(tag)
+                    assert len(sizes) <= 1
+                    if sizes:
+                        regions.append((name, origin, f'{sizes[0].text} * 1024'))
 
-                flash = flashs[0].text
-                ram = rams[0].text
+                for name, origin, length in FIXED_REGIONS[FEATURE]:
+                    regions.append((name, origin, str(length)))
 
                 assert mcu_name not in mem_info
-                mem_info[mcu_name] = {'ram': ram, 'flash': flash}
+                mem_info[mcu_name] = tuple(regions)
 
     return mem_info
 
 
-def get_mem_sizes_string(mem_info: dict):
-    outstring = 'pub static MEMORY_SIZES: Map<&str, MemSize> = phf_map! {\n'
+def get_static_name(regions: tuple):
+    # e.g. FLASH_32K_RAM_6K, regions with fixed sizes are the same for the whole family
+    return '_'.join(f'{name}_{length.split()[0]}K' for name, _, length in regions if length.endswith('* 1024'))
+
+
+def get_mem_regions_string(mem_info: dict):
+    outstring = 'pub static MEMORY_REGIONS: Map<&str, &[MemRegion]> = phf_map! {\n'
+
+    for mcu, regions in natsorted(mem_info.items()):
+        outstring += f'    "{mcu}" => {get_static_name(regions)},\n'
+
+    outstring += '};\n'
 
-    for mcu, mem_size in natsorted(mem_info.items()):
-        flash = mem_size['flash']
-        ram = mem_size['ram']
-        outstring += f'    "{mcu}" => MemSize{{flash: {flash}, ram: {ram}}},\n'
+    for regions in natsorted(set(mem_info.values()), key=get_static_name):
+        outstring += f'\nstatic {get_static_name(regions)}: &[MemRegion] = &[\n'
+        for name, origin, length in regions:
+            origin = f'{origin:08X}'
+            outstring += f'    MemRegion {{name: "{name}", origin: 0x{origin[:4]}_{origin[4:]}, length: {length}}},\n'
+        outstring += '];\n'
 
-    return outstring + '};\n'
+    return outstring
 
 
 if __name__ == '__main__':
-    mem_info = get_memory_sizes()
-    mem_sizes_string = get_mem_sizes_string(mem_info)
+    mem_info = get_memory_regions()
+    mem_regions_string = get_mem_regions_string(mem_info)
 
-    imports = 'use phf::{phf_map, Map};\n'\
-              'use super::MemSize;\n\n'
+    imports = 'use super::MemRegion;\n' \
+              'use phf::{phf_map, Map};\n\n'
 
-    print(imports + mem_sizes_string)
+    print(imports + mem_regions_string, end='')
@@ -0,0 +1,84 @@
+import xml.etree.ElementTree as ElementTree
+import re
+from pathlib import Path
+
+from natsort import natsorted
+
+db_path = Path(r'C:\Program Files (x86)\STMicroelectronics\STM32Cube\STM32CubeMX\db\mcu')
+FEATURE = 'F0'
+
+ns = '{http://mcd.rou.st.com/modules.php?name=mcu}'
+p = ns + 'Pin'
+
+pin_types = {
+    'I/O': 'Io',
+    'Power': 'Power',
+    'Reset': 'Reset',
+    'Boot': 'Boot',
+}
+
+
+def print_red(*args):
+    print('\033[31m', end='')
+    print(*args, end='')
+    print('\033[0m')
+
+
+def get_pinouts():
+    """maps every MCU xml name (Mcu.Name in the ioc) to its package and pin list"""
+    pinouts = {}
+
+    root = ElementTree.parse(str(db_path / 'families.xml')).getroot()
+
+    for family in root:
+        for subfamily in family:
+            for mcu in subfamily:
+                name = mcu.attrib['Name']
+                if not name.startswith('STM32' + FEATURE) or name in pinouts:
+                    continue
+
+                mcu_root = ElementTree.parse(str(db_path / (name + '.xml'))).getroot()
+
+                pins = []
+                for pin in mcu_root.findall(p):
+                    pin_type = pin_types.get(pin.attrib['Type'])
+                    if pin_type is None:
+                        print_red(name, pin.attrib['Name'], pin.attrib['Type'])
+                        continue
+                    pins.append((pin.attrib['Position'], pin.attrib['Name'], pin_type))
+
+                pinouts[name] = (mcu_root.attrib['Package'], pins)
+
+    return pinouts
+
+
+def static_name(mcu_name: str):
+    # "STM32F042C(4-6)Tx" -> "STM32F042C_4_6_TX"
+    return re.sub(r'\W+', '_', mcu_name).strip('_').upper()
+
+
+if __name__ == '__main__':
+    pinouts = get_pinouts()
+
+    # identical pin lists share one static
+    statics = {}
+
+    pinouts_string = 'pub static PINOUTS: Map<&str, Pinout> = phf_map! {\n'
+    for name, (package, pins) in natsorted(pinouts.items()):
+        key = tuple(pins)
+        if key not in statics:
+            statics[key] = static_name(name)
+        pinouts_string += f'    "{name}" => Pinout {{package: "{package}", pins: {statics[key]}}},\n'
+    pinouts_string += '};\n'
+
+    outstring = ''
+    for pins, static in statics.items():
+        outstring += f'\nstatic {static}: &[Pin] = &[\n'
+        for position, name, pin_type in pins:
+            outstring += f'    Pin {{position: "{position}", name: "{name}", pin_type: {pin_type}}},\n'
+        outstring += '];\n'
+
+    imports = 'use super::{Pin, PinType::*, Pinout};\n' \
+              'use phf::{phf_map, Map};\n\n'
+
+    print(imports + pinouts_string + outstring, end='')
@@ -4,15 +4,16 @@ from pathlib import Path
 
 from natsort import natsorted
 
-dir_path = Path(r'C:\Program Files (x86)\STMicroelectronics\STM32Cube\STM32CubeMX\db\mcu\IP')
+db_path = Path(r'C:\Program Files (x86)\STMicroelectronics\STM32Cube\STM32CubeMX\db\mcu')
 FEATURE = 'F0'
 
-p = '{http://mcd.rou.st.com/modules.php?name=mcu}GPIO_Pin'
-s = '{http://mcd.rou.st.com/modules.php?name=mcu}PinSignal'
-sp = '{http://mcd.rou.st.com/modules.php?name=mcu}SpecificParameter'
-pv = '{http://mcd.rou.st.com/modules.php?name=mcu}PossibleValue'
+ns = '{http://mcd.rou.st.com/modules.php?name=mcu}'
+p = ns + 'GPIO_Pin'
+s = ns + 'PinSignal'
+sp = ns + 'SpecificParameter'
+pv = ns + 'PossibleValue'
+ip = ns + 'IP'
 
-re_chip_name = re.compile(r'^GPIO-STM32(\w*)_gpio\w*.xml$')
 re_af_name = re.compile(r'^GPIO_(AF\d*)_\w*$')
 re_pin_name = re.compile(r'^P[A-K]\d{1,2}')
 
@@ -23,82 +24,98 @@ def print_red(*args):
     print('\033[0m')
 
 
-def transpose(din):
-    dout = {}
-    for key1, inner in din.items():
-        for key2, value in inner.items():
-            dout.setdefault(key2, {})[key1] = value
-    return dout
+def get_gpio_versions():
+    """maps every MCU RefName (Mcu.UserName in the ioc) to the GPIO IP version referenced in its MCU xml"""
+    versions = {}
 
+    root = ElementTree.parse(str(db_path / 'families.xml')).getroot()
 
-def get_pins():
-    pins = {}
+    for family in root:
+        for subfamily in family:
+            for mcu in subfamily:
+                ref_name = mcu.attrib['RefName']
+                if not ref_name.startswith('STM32' + FEATURE):
+                    continue
 
-    for path in dir_path.iterdir():
-        if not path.name.startswith('GPIO-STM32' + FEATURE):
-            continue
-        chip_type = re_chip_name.match(path.name).group(1)
-
-        tree = ElementTree.parse(str(path))
-        root = tree.getroot()
-
-        for pin in root.findall(p):
-            for signal in pin.findall(s):
-                for parameter in signal.findall(sp):
-                    for possible_value in parameter.findall(pv):
-                        pin_name = re_pin_name.findall(pin.attrib['Name'])[0]
-                        signal_name = signal.attrib['Name']
-                        try:
-                            af = re_af_name.match(possible_value.text).group(1)
-                            pins \
-                                .setdefault(pin_name, {}) \
-                                .setdefault(signal_name, {}) \
-                                .setdefault(af, []).append(chip_type)
-                        except AttributeError:
-                            print_red(path, possible_value.text)
+                mcu_root = ElementTree.parse(str(db_path / (mcu.attrib['Name'] + '.xml'))).getroot()
+                gpio_ips = [i.attrib['Version'] for i in mcu_root.findall(ip) if i.attrib['Name'] == 'GPIO']
 
-    return pins
+                if len(gpio_ips) != 1:
+                    print_red(ref_name, 'has GPIO IPs', gpio_ips)
+                    continue
 
+                versions[ref_name] = gpio_ips[0]
 
-def get_afs(pattern: re.Pattern):
-    all_pins = get_pins()
-    all_signals = transpose(all_pins)
+    return versions
 
-    outstring = ''
 
-    for signal, pins in natsorted(all_signals.items()):
-        if not pattern.match(signal):
+def get_pins(version: str):
+    """returns {pin: {signal: [af, ...]}} for one GPIO IP version"""
+    pins = {}
+
+    path = db_path / 'IP' / f'GPIO-{version}_Modes.xml'
+    root = ElementTree.parse(str(path)).getroot()
+
+    for pin in root.findall(p):
+        # the F042 xml also lists the remapped pins of the small packages, e.g. "PA11 [PA9]",
+        # their entries would be merged into the real pin and shadow its alternate functions
+        if '[' in pin.attrib['Name']:
             continue
+        pin_name = re_pin_name.findall(pin.attrib['Name'])[0]
+        for signal in pin.findall(s):
+            signal_name = signal.attrib['Name']
+            # the values can be nested in a RemapBlock
+            for parameter in signal.iter(sp):
+                if parameter.attrib.get('Name') != 'GPIO_AF':
+                    continue
+                for possible_value in parameter.findall(pv):
+                    match = re_af_name.match(possible_value.text)
+                    if match is None:
+                        print_red(path, possible_value.text)
+                        continue
+                    afs = pins.setdefault(pin_name, {}).setdefault(signal_name, [])
+                    af = int(match.group(1)[2:])
+                    if af not in afs:
+                        afs.append(af)
+
+    return pins
 
-        outstring += f'static {signal}: Map<&str, u8> = phf_map! {{\n'
-        for pin, afs in natsorted(pins.items()):
-            assert len(afs) == 1
-            af = list(afs.keys())[0]
-            outstring += f'    "{pin.lower()}" => {af[-1]},\n'
-        outstring += '};\n\n'
 
-        mapped_signals.append(signal)
+def get_af_map(version: str):
+    outstring = f'static {version.upper()}: AfMap = phf_map! {{\n'
 
-    return outstring
+    for pin, signals in natsorted(get_pins(version).items()):
+        entries = []
+        for signal, afs in natsorted(signals.items()):
+            # a signal has to map to a single AF on a pin, report the conflict instead of guessing
+            if len(afs) != 1:
+                print_red(f'conflict in {version}: {pin} {signal} has AFs {afs}')
+                continue
+            entries.append((afs[0], signal))
+
+        if entries:
+            entries_string = ', '.join(f'("{signal}", {af})' for af, signal in sorted(entries))
+            outstring += f'    "{pin.lower()}" => &[{entries_string}],\n'
+
+    return outstring + '};\n'
 
 
 if __name__ == '__main__':
-    mapped_signals = []
+    gpio_versions = get_gpio_versions()
 
-    re_spis = re.compile(r'^SPI\d_(SCK|MISO|MOSI)')
-    re_i2cs = re.compile(r'^I2C\d_(SCL|SDA)')
-    re_uarts = re.compile(r'^USART\d_(TX|RX)')
+    versions_string = 'pub static GPIO_VERSIONS: Map<&str, &str> = phf_map! {\n'
+    for mcu, version in natsorted(gpio_versions.items()):
+        versions_string += f'    "{mcu}" => "{version}",\n'
+    versions_string += '};\n\n'
 
+    af_maps_string = 'pub static AF_MAPS: Map<&str, &AfMap> = phf_map! {\n'
     outstring = ''
-    outstring += get_afs(re_i2cs)
-    outstring += get_afs(re_spis)
-    outstring += get_afs(re_uarts)
-
-    af_map_string = 'pub static AF_MAP: Map<&str, &Map<&str, u8>> = phf_map! {\n'
-    for signal in mapped_signals:
-        af_map_string += f'    "{signal}" => &{signal},\n'
-    af_map_string += '};\n\n'
+    for version in natsorted(set(gpio_versions.values())):
+        af_maps_string += f'    "{version}" => &{version.upper()},\n'
+        outstring += '\n' + get_af_map(version)
+    af_maps_string += '};\n'
 
-    imports = 'use phf::{phf_map, Map};\n\n'
+    imports = 'use super::AfMap;\n' \
+              'use phf::{phf_map, Map};\n\n'
 
-    print(imports + af_map_string + outstring)
+    print(imports + versions_string + af_maps_string + outstring, end='')
@@ -0,0 +1,185 @@
+use regex::Regex;
+
+use crate::db::has_adc_vbat;
+use crate::gpio::SignalType;
+use crate::rcc::find_pin_with_signal;
+use crate::*;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct ADC {
+    /// the regular conversions ordered by rank
+    pub channels: Vec<AdcChannel>,
+    pub resolution: Option<Resolution>,
+    pub sampling_time: Option<SamplingTime>,
+    pub data_align: Option<DataAlign>,
+    pub continuous: bool,
+    pub scan_direction: Option<ScanDirection>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AdcChannel {
+    /// input channel on a pin, e.g. 1 for ADC_IN1
+    Input(u8),
+    TempSensor,
+    VRefInt,
+    VBat,
+}
+
+pub fn get_adc(config: &ConfigParams<'_>) -> anyhow::Result<Option<ADC>> {
+    let adc_params = match config.get("ADC") {
+        Some(adc_params) => adc_params,
+        None => return Ok(None),
+    };
+
+    // e.g. ADC.Channel-0\#ChannelRegularConversion=ADC_CHANNEL_1
+    // and ADC.Rank-0\#ChannelRegularConversion=1
+    let re = Regex::new(r"^Channel-(\d+)\\#ChannelRegularConversion$").unwrap();
+
+    let mut ranked_channels = Vec::new();
+    for (name, &value) in adc_params.iter() {
+        let index = match re.captures(name) {
+            Some(captures) => captures[1].to_string(),
+            None => continue,
+        };
+
+        let rank = parse_optional_u32(adc_params, &f!("Rank-{index}\\#ChannelRegularConversion"))?
+            .unwrap_or_default();
+
+        let channel = match value {
+            "ADC_CHANNEL_TEMPSENSOR" => AdcChannel::TempSensor,
+            "ADC_CHANNEL_VREFINT" => AdcChannel::VRefInt,
+            "ADC_CHANNEL_VBAT" => AdcChannel::VBat,
+            _ => {
+                let number: u8 = value
+                    .strip_prefix("ADC_CHANNEL_")
+                    .and_then(|number| number.parse().ok())
+                    .ok_or_else(|| anyhow!("invalid ADC.{} {}", name, value))?;
+                ensure!(
+                    find_pin_with_signal(config, &f!("ADC_IN{number}")).is_some(),
+                    "{} is converted but no pin has the ADC_IN{} signal",
+                    value,
+                    number
+                );
+                AdcChannel::Input(number)
+            }
+        };
+
+        ranked_channels.push((rank, channel));
+    }
+    ranked_channels.sort_by_key(|&(rank, _)| rank);
+
+    Ok(Some(ADC {
+        channels: ranked_channels
+            .into_iter()
+            .map(|(_, channel)| channel)
+            .collect(),
+        resolution: parse_optional_param(adc_params, "Resolution")?,
+        sampling_time: parse_optional_param(adc_params, "SamplingTimeCommon")?,
+        data_align: parse_optional_param(adc_params, "DataAlign")?,
+        continuous: adc_params.get("ContinuousConvMode") == Some(&"ENABLE"),
+        scan_direction: parse_optional_param(adc_params, "ScanConvMode")?,
+    }))
+}
+
+impl AdcChannel {
+    /// The pin of an input channel
+    pub fn get_gpio<'a>(&self, config: &'a Config) -> Option<&'a GpioPin> {
+        match *self {
+            AdcChannel::Input(channel) => config
+                .gpios
+                .iter()
+                .find(|gpio| gpio.signal == SignalType::AdcChannel(channel)),
+            _ => None,
+        }
+    }
+
+    /// The field name in the generated AdcChannels, the pin name for input channels
+    pub fn get_name(&self, config: &Config) -> String {
+        match self {
+            AdcChannel::Input(channel) => match self.get_gpio(config) {
+                Some(gpio) => gpio.get_name(),
+                None => f!("adc_in{channel}"),
+            },
+            AdcChannel::TempSensor => "temperature".to_string(),
+            AdcChannel::VRefInt => "vrefint".to_string(),
+            AdcChannel::VBat => "vbat".to_string(),
+        }
+    }
+}
+
+/// The HAL only does single conversions of the channels the MCU has, the channel names have to be unique fields
+pub fn validate_adc(config: &Config) -> anyhow::Result<()> {
+    let adc = match &config.adc {
+        Some(adc) => adc,
+        None => return Ok(()),
+    };
+
+    let mut names: Vec<String> = Vec::new();
+    for channel in adc.channels.iter() {
+        let name = channel.get_name(config);
+        ensure!(
+            !names.contains(&name),
+            "More than one ADC channel uses the name {}",
+            name
+        );
+        names.push(name);
+    }
+
+    ensure!(
+        !adc.continuous,
+        "ADC.ContinuousConvMode=ENABLE isn't supported by the HAL, it only does single conversions"
+    );
+
+    ensure!(
+        adc.scan_direction != Some(ScanDirection::ADC_SCAN_DIRECTION_BACKWARD),
+        "ADC.ScanConvMode=ADC_SCAN_DIRECTION_BACKWARD isn't supported by the HAL, it reads the channels one by one"
+    );
+
+    ensure!(
+        !adc.channels.contains(&AdcChannel::VBat)
+            || has_adc_vbat(config.mcu_family, &config.mcu_name),
+        "{} has no ADC_CHANNEL_VBAT",
+        config.mcu_name
+    );
+
+    Ok(())
+}
+
+parameter!(
+    Resolution,
+    [
+        ADC_RESOLUTION_12B,
+        ADC_RESOLUTION_10B,
+        ADC_RESOLUTION_8B,
+        ADC_RESOLUTION_6B
+    ],
+    default = ADC_RESOLUTION_12B
+);
+
+parameter!(
+    SamplingTime,
+    [
+        ADC_SAMPLETIME_1CYCLE_5,
+        ADC_SAMPLETIME_7CYCLES_5,
+        ADC_SAMPLETIME_13CYCLES_5,
+        ADC_SAMPLETIME_28CYCLES_5,
+        ADC_SAMPLETIME_41CYCLES_5,
+        ADC_SAMPLETIME_55CYCLES_5,
+        ADC_SAMPLETIME_71CYCLES_5,
+        ADC_SAMPLETIME_239CYCLES_5
+    ],
+    default = ADC_SAMPLETIME_1CYCLE_5
+);
+
+parameter!(
+    DataAlign,
+    [ADC_DATAALIGN_RIGHT, ADC_DATAALIGN_LEFT],
+    default = ADC_DATAALIGN_RIGHT
+);
+
+parameter!(
+    ScanDirection,
+    [ADC_SCAN_DIRECTION_FORWARD, ADC_SCAN_DIRECTION_BACKWARD],
+    default = ADC_SCAN_DIRECTION_FORWARD
+);
@@ -0,0 +1,346 @@
+use crate::db::*;
+use crate::rcc::*;
+use crate::*;
+
+const HSI14_FREQ: u32 = 14_000_000;
+const HSI48_FREQ: u32 = 48_000_000;
+/// the HDMI-CEC kernel clock is HSI divided by 244
+const HSI_CEC_DIVIDER: u32 = 244;
+
+/// A clock of the clock tree with its frequency in Hz
+#[derive(Debug)]
+pub struct Clock {
+    /// name of the clock, also used for the generated constant
+    pub name: &'static str,
+    pub freq: u32,
+    /// the ioc key CubeMX writes the computed frequency to
+    pub ioc_key: Option<&'static str>,
+    /// the clock this one is derived from, None for oscillators
+    pub source: Option<&'static str>,
+    /// the multiplier or divider between the source and this clock, e.g. "PLLMUL x6"
+    pub setting: Option<String>,
+}
+
+/// All clocks of the MCU, from the sources through the PLL to the buses and peripherals
+#[derive(Debug, Default)]
+pub struct ClockTree {
+    pub clocks: Vec<Clock>,
+}
+
+impl ClockTree {
+    fn add(&mut self, name: &'static str, freq: u32, ioc_key: Option<&'static str>) -> u32 {
+        self.clocks.push(Clock {
+            name,
+            freq,
+            ioc_key,
+            source: None,
+            setting: None,
+        });
+        freq
+    }
+
+    /// Adds a clock derived from the already added clock `source`
+    fn derive(
+        &mut self,
+        name: &'static str,
+        source: &'static str,
+        setting: Option<String>,
+        freq: u32,
+        ioc_key: Option<&'static str>,
+    ) -> u32 {
+        self.clocks.push(Clock {
+            name,
+            freq,
+            ioc_key,
+            source: Some(source),
+            setting,
+        });
+        freq
+    }
+
+    pub fn get(&self, name: &str) -> Option<u32> {
+        self.clocks
+            .iter()
+            .find(|clock| clock.name == name)
+            .map(|clock| clock.freq)
+    }
+}
+
+/// Computes every frequency of the clock tree from the sources, multipliers and dividers
+pub fn get_clock_tree(
+    rcc: &RCC,
+    mcu_family: MCUFamily,
+    mcu_name: &str,
+) -> anyhow::Result<ClockTree> {
+    let mut tree = ClockTree::default();
+
+    // without a model of the clock tree there is nothing to validate against the ioc file
+    if mcu_family != MCUFamily::STM32F0 {
+        print_warning(f!(
+            "No clock tree known for {mcu_family:?}, the frequencies aren't checked"
+        ));
+        return Ok(tree);
+    }
+
+    // sources
+    let hsi = tree.add("HSI", HSI_FREQ, None);
+    tree.add("HSI14", HSI14_FREQ, None);
+    let hsi48 = tree.add("HSI48", HSI48_FREQ, None);
+    let lsi = tree.add("LSI", rcc.lsi_freq, None);
+    let lse = rcc.lse.as_ref().map(|lse| tree.add("LSE", lse.freq, None));
+
+    let hse = match rcc.clock_source {
+        ClockSource::HSE(HSEMode::NotBypassed(freq))
+        | ClockSource::HSE(HSEMode::Bypassed(freq)) => Some(tree.add("HSE", freq, None)),
+        _ => None,
+    };
+
+    let sys_clock_source = rcc.sys_clock_source.unwrap_or_default();
+
+    // PLL
+    let pll_used = sys_clock_source == SYSCLKSourceType::RCC_SYSCLKSOURCE_PLLCLK
+        || rcc.usb_clock_source == Some(USBClockSource::RCC_USBCLKSOURCE_PLL)
+        || rcc.mco.as_ref().map_or(false, |mco| {
+            matches!(
+                mco.source,
+                MCOSource::RCC_MCO1SOURCE_PLLCLK | MCOSource::RCC_MCO1SOURCE_PLLCLK_DIV2
+            )
+        });
+    let pll_freq = if pll_used {
+        let prediv = rcc.prediv.unwrap_or_default().divider();
+        let prediv_setting = Some(f!("PREDIV /{prediv}"));
+        let (pll_source, pll_input, setting) = match rcc.pll_clock_source {
+            None | Some(PLLSourceType::RCC_PLLSOURCE_HSI) => {
+                if has_pll_hsi_prediv(mcu_family, mcu_name) {
+                    ("HSI", hsi / prediv, prediv_setting)
+                } else {
+                    ("HSI", hsi / 2, Some("/2".to_string()))
+                }
+            }
+            Some(PLLSourceType::RCC_PLLSOURCE_HSI48) => ("HSI48", hsi48 / prediv, prediv_setting),
+            Some(PLLSourceType::RCC_PLLSOURCE_HSE) => (
+                "HSE",
+                hse.ok_or_else(|| anyhow!("PLL source HSE isn't configured"))? / prediv,
+                prediv_setting,
+            ),
+        };
+        let pll_input = tree.derive(
+            "PLL_INPUT",
+            pll_source,
+            setting,
+            pll_input,
+            Some("VCOOutput2Freq_Value"),
+        );
+
+        let pll_mul = rcc
+            .pll_mul
+            .ok_or_else(|| anyhow!("RCC.PLLMUL required for the PLL"))?
+            .factor();
+        Some(tree.derive(
+            "PLLCLK",
+            "PLL_INPUT",
+            Some(f!("PLLMUL x{pll_mul}")),
+            pll_input * pll_mul,
+            Some("PLLCLKFreq_Value"),
+        ))
+    } else {
+        None
+    };
+
+    // system and buses
+    let (sysclk_source, sysclk) = match sys_clock_source {
+        SYSCLKSourceType::RCC_SYSCLKSOURCE_HSI => ("HSI", hsi),
+        SYSCLKSourceType::RCC_SYSCLKSOURCE_HSI48 => ("HSI48", hsi48),
+        SYSCLKSourceType::RCC_SYSCLKSOURCE_HSE => (
+            "HSE",
+            hse.ok_or_else(|| anyhow!("SYSCLK source HSE isn't configured"))?,
+        ),
+        SYSCLKSourceType::RCC_SYSCLKSOURCE_PLLCLK => ("PLLCLK", pll_freq.unwrap()),
+    };
+    let sysclk = tree.derive(
+        "SYSCLK",
+        sysclk_source,
+        None,
+        sysclk,
+        Some("SYSCLKFreq_VALUE"),
+    );
+
+    let ahb_divider = rcc.ahb_divider.unwrap_or_default().divider();
+    let hclk = tree.derive(
+        "HCLK",
+        "SYSCLK",
+        Some(f!("AHB /{ahb_divider}")),
+        sysclk / ahb_divider,
+        Some("HCLKFreq_Value"),
+    );
+    tree.derive("AHB", "HCLK", None, hclk, Some("AHBFreq_Value"));
+    tree.derive(
+        "FCLK_CORTEX",
+        "HCLK",
+        None,
+        hclk,
+        Some("FCLKCortexFreq_Value"),
+    );
+    tree.derive("SYSTICK", "HCLK", None, hclk, Some("TimSysFreq_Value"));
+
+    let apb1_divider = rcc.apb1_divider.unwrap_or_default().divider();
+    let pclk1 = tree.derive(
+        "PCLK1",
+        "HCLK",
+        Some(f!("APB1 /{apb1_divider}")),
+        hclk / apb1_divider,
+        Some("APB1Freq_Value"),
+    );
+
+    // timers run at twice the bus clock if APB1 is divided
+    let timer_multiplier = if apb1_divider == 1 { 1 } else { 2 };
+    tree.derive(
+        "APB1_TIMER",
+        "PCLK1",
+        Some(f!("x{timer_multiplier}")),
+        pclk1 * timer_multiplier,
+        Some("APB1TimFreq_Value"),
+    );
+
+    // peripheral kernel clocks
+    for switch in KERNEL_CLOCK_SWITCHES.iter() {
+        let is_set = rcc
+            .kernel_clocks
+            .iter()
+            .any(|kernel_clock| std::ptr::eq(kernel_clock.switch, switch));
+        if switch.optional && !is_set {
+            continue;
+        }
+
+        let source = rcc.kernel_clock_source(switch);
+        let kernel_clock = match source {
+            KernelClockSource::PCLK1 => Some(("PCLK1", pclk1, None)),
+            KernelClockSource::SYSCLK => Some(("SYSCLK", sysclk, None)),
+            KernelClockSource::LSE => lse.map(|lse| ("LSE", lse, None)),
+            KernelClockSource::HSI if switch.peripheral == "CEC" => {
+                Some(("HSI", hsi / HSI_CEC_DIVIDER, Some(f!("/{HSI_CEC_DIVIDER}"))))
+            }
+            KernelClockSource::HSI => Some(("HSI", hsi, None)),
+        };
+        if let Some((source, freq, setting)) = kernel_clock {
+            tree.derive(
+                switch.peripheral,
+                source,
+                setting,
+                freq,
+                Some(switch.freq_key),
+            );
+        }
+    }
+    tree.derive("I2S", "SYSCLK", None, sysclk, Some("I2SFreq_Value"));
+    tree.derive(
+        "HSI_CEC",
+        "HSI",
+        Some(f!("/{HSI_CEC_DIVIDER}")),
+        hsi / HSI_CEC_DIVIDER,
+        Some("HSICECFreq_Value"),
+    );
+
+    match rcc.usb_clock_source {
+        Some(USBClockSource::RCC_USBCLKSOURCE_HSI48) => {
+            tree.derive("USB", "HSI48", None, hsi48, Some("USBFreq_Value"));
+        }
+        Some(USBClockSource::RCC_USBCLKSOURCE_PLL) => {
+            tree.derive(
+                "USB",
+                "PLLCLK",
+                None,
+                pll_freq.unwrap(),
+                Some("USBFreq_Value"),
+            );
+        }
+        None => {}
+    }
+
+    if let Some(mco) = &rcc.mco {
+        let source = match mco.source {
+            MCOSource::RCC_MCO1SOURCE_NOCLOCK => None,
+            MCOSource::RCC_MCO1SOURCE_HSI14 => Some(("HSI14", HSI14_FREQ, 1)),
+            MCOSource::RCC_MCO1SOURCE_LSI => Some(("LSI", lsi, 1)),
+            MCOSource::RCC_MCO1SOURCE_LSE => lse.map(|lse| ("LSE", lse, 1)),
+            MCOSource::RCC_MCO1SOURCE_SYSCLK => Some(("SYSCLK", sysclk, 1)),
+            MCOSource::RCC_MCO1SOURCE_HSI => Some(("HSI", hsi, 1)),
+            MCOSource::RCC_MCO1SOURCE_HSE => hse.map(|hse| ("HSE", hse, 1)),
+            MCOSource::RCC_MCO1SOURCE_PLLCLK_DIV2 => {
+                pll_freq.map(|pll_freq| ("PLLCLK", pll_freq, 2))
+            }
+            MCOSource::RCC_MCO1SOURCE_HSI48 => Some(("HSI48", hsi48, 1)),
+            MCOSource::RCC_MCO1SOURCE_PLLCLK => pll_freq.map(|pll_freq| ("PLLCLK", pll_freq, 1)),
+        };
+        match source {
+            Some((source, freq, pre_divider)) => {
+                let divider = pre_divider * mco.divider.divider();
+                tree.derive(
+                    "MCO",
+                    source,
+                    Some(f!("MCOPRE /{divider}")),
+                    freq / divider,
+                    Some("MCOFreq_Value"),
+                );
+            }
+            None if mco.source == MCOSource::RCC_MCO1SOURCE_NOCLOCK => {
+                tree.add("MCO", 0, Some("MCOFreq_Value"));
+            }
+            None => {}
+        }
+    }
+
+    // backup domain
+    let rtc = match rcc.rtc_clock_source {
+        Some(RTCClockSource::RCC_RTCCLKSOURCE_LSE) => lse.map(|lse| ("LSE", lse, None)),
+        Some(RTCClockSource::RCC_RTCCLKSOURCE_LSI) => Some(("LSI", lsi, None)),
+        Some(RTCClockSource::RCC_RTCCLKSOURCE_HSE_DIV32) => {
+            hse.map(|hse| ("HSE", hse / 32, Some("/32".to_string())))
+        }
+        None => None,
+    };
+    if let Some((source, freq, setting)) = rtc {
+        tree.derive("RTC", source, setting, freq, Some("RTCFreq_Value"));
+    }
+
+    Ok(tree)
+}
+
+/// Compares the computed frequencies with the ones CubeMX wrote to the ioc file
+pub fn validate_clock_tree(
+    config_params: &ConfigParams<'_>,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let rcc_params = match config_params.get("RCC") {
+        Some(rcc_params) => rcc_params,
+        None => return Ok(()),
+    };
+
+    let mut errors = Vec::new();
+
+    for clock in config.clocks.clocks.iter() {
+        let ioc_key = match clock.ioc_key {
+            Some(ioc_key) => ioc_key,
+            None => continue,
+        };
+        let ioc_value = match rcc_params.get(ioc_key) {
+            Some(ioc_value) => ioc_value,
+            None => continue,
+        };
+
+        // some frequencies like the CEC clock aren't integers
+        let ioc_freq: f64 = ioc_value
+            .parse()
+            .map_err(|_| anyhow!("RCC.{} parameter invalid number", ioc_key))?;
+
+        if (ioc_freq - clock.freq as f64).abs() >= 1.0 {
+            errors.push(f!(
+                "RCC.{ioc_key}={ioc_value} but the computed {clock.name} is {clock.freq} Hz"
+            ));
+        }
+    }
+
+    ensure!(errors.is_empty(), errors.join("\n"));
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use std::convert::TryFrom;
+
+use regex::Regex;
+
+use crate::db::has_dac_channel;
+use crate::gpio::SignalType;
+use crate::*;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct DAC {
+    pub channels: Vec<DacChannel>,
+}
+
+#[derive(Debug)]
+pub struct DacChannel {
+    /// 1 for DAC_OUT1, 2 for DAC_OUT2
+    pub channel: u8,
+    pub output_buffer: Option<OutputBuffer>,
+    pub trigger: Option<Trigger>,
+    pub wave_generation: Option<WaveGeneration>,
+    /// MAMP value, the LFSR mask for noise or the triangle amplitude
+    pub amplitude: Option<u8>,
+}
+
+pub fn get_dac(config: &ConfigParams<'_>) -> anyhow::Result<Option<DAC>> {
+    let empty = HashMap::new();
+    let dac_params = config.get("DAC").unwrap_or(&empty);
+
+    let mut channels = Vec::new();
+    for channel in 1..=2 {
+        // a channel is used if its pin is, the settings are optional
+        if !config.values().any(|params| {
+            params
+                .get("Signal")
+                .and_then(|&signal| SignalType::try_from(signal).ok())
+                == Some(SignalType::DacOutput(channel))
+        }) {
+            continue;
+        }
+
+        // e.g. DAC.DAC_Trigger-DAC_OUT1, CubeMX leaves out the suffix with a single channel
+        let get_param = |name: &str| -> Option<&str> {
+            dac_params
+                .get(f!("{name}-DAC_OUT{channel}").as_str())
+                .or_else(|| dac_params.get(name).filter(|_| channel == 1))
+                .copied()
+        };
+
+        let output_buffer = get_param("DAC_OutputBuffer")
+            .map(OutputBuffer::try_from)
+            .transpose()?;
+        let trigger = get_param("DAC_Trigger")
+            .map(Trigger::try_from)
+            .transpose()?;
+        let wave_generation = get_param("DAC_WaveGeneration")
+            .map(WaveGeneration::try_from)
+            .transpose()?;
+        let amplitude = get_param("DAC_LFSRUnmask_TriangleAmplitude")
+            .map(parse_amplitude)
+            .transpose()?;
+
+        channels.push(DacChannel {
+            channel,
+            output_buffer,
+            trigger,
+            wave_generation,
+            amplitude,
+        });
+    }
+
+    if channels.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(DAC { channels }))
+}
+
+/// The MAMP bits, e.g. 3 for DAC_LFSRUNMASK_BITS3_0 or DAC_TRIANGLEAMPLITUDE_15
+fn parse_amplitude(text: &str) -> anyhow::Result<u8> {
+    let re = Regex::new(r"^DAC_(LFSRUNMASK_BITS?|TRIANGLEAMPLITUDE_)(\d+)(_0)?$").unwrap();
+
+    let captures = re
+        .captures(text)
+        .ok_or_else(|| anyhow!("invalid DAC amplitude {}", text))?;
+    let number: u32 = captures[2].parse()?;
+
+    let amplitude = if &captures[1] == "TRIANGLEAMPLITUDE_" {
+        // 2^(n + 1) - 1
+        ensure!(
+            (number + 1).is_power_of_two() && number > 0,
+            "invalid DAC amplitude {}",
+            text
+        );
+        (number + 1).trailing_zeros() - 1
+    } else {
+        number
+    };
+
+    ensure!(amplitude <= 11, "invalid DAC amplitude {}", text);
+
+    Ok(amplitude as u8)
+}
+
+/// The MCU needs the used channels, wave generation only advances on a trigger
+pub fn validate_dac(config: &Config) -> anyhow::Result<()> {
+    let dac = match &config.dac {
+        Some(dac) => dac,
+        None => return Ok(()),
+    };
+
+    ensure!(has_dac_channel(config, 1), "{} has no DAC", config.mcu_name);
+
+    for channel in dac.channels.iter() {
+        ensure!(
+            has_dac_channel(config, channel.channel),
+            "{} has no DAC_OUT{}",
+            config.mcu_name,
+            channel.channel
+        );
+
+        let wave_generation = channel.wave_generation.unwrap_or_default();
+        ensure!(
+            wave_generation == WaveGeneration::DAC_WAVEGENERATION_NONE
+                || channel.trigger.unwrap_or_default().tsel().is_some(),
+            "DAC_OUT{} uses {:?} without a trigger",
+            channel.channel,
+            wave_generation
+        );
+    }
+
+    Ok(())
+}
+
+parameter!(
+    OutputBuffer,
+    [DAC_OUTPUTBUFFER_ENABLE, DAC_OUTPUTBUFFER_DISABLE],
+    default = DAC_OUTPUTBUFFER_ENABLE
+);
+
+parameter!(
+    Trigger,
+    [
+        DAC_TRIGGER_NONE,
+        DAC_TRIGGER_T6_TRGO,
+        DAC_TRIGGER_T3_TRGO,
+        DAC_TRIGGER_T7_TRGO,
+        DAC_TRIGGER_T15_TRGO,
+        DAC_TRIGGER_T2_TRGO,
+        DAC_TRIGGER_EXT_IT9,
+        DAC_TRIGGER_SOFTWARE
+    ],
+    default = DAC_TRIGGER_NONE
+);
+
+impl Trigger {
+    /// The TSEL bits, None if the trigger is disabled
+    pub fn tsel(self) -> Option<u8> {
+        match self {
+            Trigger::DAC_TRIGGER_NONE => None,
+            Trigger::DAC_TRIGGER_T6_TRGO => Some(0b000),
+            Trigger::DAC_TRIGGER_T3_TRGO => Some(0b001),
+            Trigger::DAC_TRIGGER_T7_TRGO => Some(0b010),
+            Trigger::DAC_TRIGGER_T15_TRGO => Some(0b011),
+            Trigger::DAC_TRIGGER_T2_TRGO => Some(0b100),
+            Trigger::DAC_TRIGGER_EXT_IT9 => Some(0b110),
+            Trigger::DAC_TRIGGER_SOFTWARE => Some(0b111),
+        }
+    }
+}
+
+parameter!(
+    WaveGeneration,
+    [
+        DAC_WAVEGENERATION_NONE,
+        DAC_WAVEGENERATION_NOISE,
+        DAC_WAVEGENERATION_TRIANGLE
+    ],
+    default = DAC_WAVEGENERATION_NONE
+);
@@ -1,201 +1,395 @@
+use super::AfMap;
 use phf::{phf_map, Map};
 
-pub static AF_MAP: Map<&str, &Map<&str, u8>> = phf_map! {
-    "I2C1_SCL" => &I2C1_SCL,
-    "I2C1_SDA" => &I2C1_SDA,
-    "I2C2_SCL" => &I2C2_SCL,
-    "I2C2_SDA" => &I2C2_SDA,
-    "SPI1_MISO" => &SPI1_MISO,
-    "SPI1_MOSI" => &SPI1_MOSI,
-    "SPI1_SCK" => &SPI1_SCK,
-    "SPI2_MISO" => &SPI2_MISO,
-    "SPI2_MOSI" => &SPI2_MOSI,
-    "SPI2_SCK" => &SPI2_SCK,
-    "USART1_RX" => &USART1_RX,
-    "USART1_TX" => &USART1_TX,
-    "USART2_RX" => &USART2_RX,
-    "USART2_TX" => &USART2_TX,
-    "USART3_RX" => &USART3_RX,
-    "USART3_TX" => &USART3_TX,
-    "USART4_RX" => &USART4_RX,
-    "USART4_TX" => &USART4_TX,
-    "USART5_RX" => &USART5_RX,
-    "USART5_TX" => &USART5_TX,
-    "USART6_RX" => &USART6_RX,
-    "USART6_TX" => &USART6_TX,
-    "USART7_RX" => &USART7_RX,
-    "USART7_TX" => &USART7_TX,
-    "USART8_RX" => &USART8_RX,
-    "USART8_TX" => &USART8_TX,
+pub static GPIO_VERSIONS: Map<&str, &str> = phf_map! {
+    "STM32F030C6Tx" => "STM32F031_gpio_v1_0",
+    "STM32F030C8Tx" => "STM32F051_gpio_v1_0",
+    "STM32F030CCTx" => "STM32F091_gpio_v1_0",
+    "STM32F030F4Px" => "STM32F031_gpio_v1_0",
+    "STM32F030K6Tx" => "STM32F031_gpio_v1_0",
+    "STM32F030R8Tx" => "STM32F051_gpio_v1_0",
+    "STM32F030RCTx" => "STM32F091_gpio_v1_0",
+    "STM32F031C4Tx" => "STM32F031_gpio_v1_0",
+    "STM32F031C6Tx" => "STM32F031_gpio_v1_0",
+    "STM32F031E6Yx" => "STM32F031_gpio_v1_0",
+    "STM32F031F4Px" => "STM32F031_gpio_v1_0",
+    "STM32F031F6Px" => "STM32F031_gpio_v1_0",
+    "STM32F031G4Ux" => "STM32F031_gpio_v1_0",
+    "STM32F031G6Ux" => "STM32F031_gpio_v1_0",
+    "STM32F031K4Ux" => "STM32F031_gpio_v1_0",
+    "STM32F031K6Tx" => "STM32F031_gpio_v1_0",
+    "STM32F031K6Ux" => "STM32F031_gpio_v1_0",
+    "STM32F038C6Tx" => "STM32F031_gpio_v1_0",
+    "STM32F038E6Yx" => "STM32F031_gpio_v1_0",
+    "STM32F038F6Px" => "STM32F031_gpio_v1_0",
+    "STM32F038G6Ux" => "STM32F031_gpio_v1_0",
+    "STM32F038K6Ux" => "STM32F031_gpio_v1_0",
+    "STM32F042C4Tx" => "STM32F042_gpio_v1_0",
+    "STM32F042C4Ux" => "STM32F042_gpio_v1_0",
+    "STM32F042C6Tx" => "STM32F042_gpio_v1_0",
+    "STM32F042C6Ux" => "STM32F042_gpio_v1_0",
+    "STM32F042F4Px" => "STM32F042_gpio_v1_0",
+    "STM32F042F6Px" => "STM32F042_gpio_v1_0",
+    "STM32F042G4Ux" => "STM32F042_gpio_v1_0",
+    "STM32F042G6Ux" => "STM32F042_gpio_v1_0",
+    "STM32F042K4Tx" => "STM32F042_gpio_v1_0",
+    "STM32F042K4Ux" => "STM32F042_gpio_v1_0",
+    "STM32F042K6Tx" => "STM32F042_gpio_v1_0",
+    "STM32F042K6Ux" => "STM32F042_gpio_v1_0",
+    "STM32F042T6Yx" => "STM32F042_gpio_v1_0",
+    "STM32F048C6Ux" => "STM32F042_gpio_v1_0",
+    "STM32F048G6Ux" => "STM32F042_gpio_v1_0",
+    "STM32F048T6Yx" => "STM32F042_gpio_v1_0",
+    "STM32F051C4Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051C4Ux" => "STM32F051_gpio_v1_0",
+    "STM32F051C6Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051C6Ux" => "STM32F051_gpio_v1_0",
+    "STM32F051C8Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051C8Ux" => "STM32F051_gpio_v1_0",
+    "STM32F051K4Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051K4Ux" => "STM32F051_gpio_v1_0",
+    "STM32F051K6Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051K6Ux" => "STM32F051_gpio_v1_0",
+    "STM32F051K8Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051K8Ux" => "STM32F051_gpio_v1_0",
+    "STM32F051R4Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051R6Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051R8Hx" => "STM32F051_gpio_v1_0",
+    "STM32F051R8Tx" => "STM32F051_gpio_v1_0",
+    "STM32F051T8Yx" => "STM32F051_gpio_v1_0",
+    "STM32F058C8Ux" => "STM32F051_gpio_v1_0",
+    "STM32F058R8Hx" => "STM32F051_gpio_v1_0",
+    "STM32F058R8Tx" => "STM32F051_gpio_v1_0",
+    "STM32F058T8Yx" => "STM32F051_gpio_v1_0",
+    "STM32F070C6Tx" => "STM32F042_gpio_v1_0",
+    "STM32F070CBTx" => "STM32F072_gpio_v1_0",
+    "STM32F070F6Px" => "STM32F042_gpio_v1_0",
+    "STM32F070RBTx" => "STM32F072_gpio_v1_0",
+    "STM32F071C8Tx" => "STM32F072_gpio_v1_0",
+    "STM32F071C8Ux" => "STM32F072_gpio_v1_0",
+    "STM32F071CBTx" => "STM32F072_gpio_v1_0",
+    "STM32F071CBUx" => "STM32F072_gpio_v1_0",
+    "STM32F071CBYx" => "STM32F072_gpio_v1_0",
+    "STM32F071RBTx" => "STM32F072_gpio_v1_0",
+    "STM32F071V8Hx" => "STM32F072_gpio_v1_0",
+    "STM32F071V8Tx" => "STM32F072_gpio_v1_0",
+    "STM32F071VBHx" => "STM32F072_gpio_v1_0",
+    "STM32F071VBTx" => "STM32F072_gpio_v1_0",
+    "STM32F072C8Tx" => "STM32F072_gpio_v1_0",
+    "STM32F072C8Ux" => "STM32F072_gpio_v1_0",
+    "STM32F072CBTx" => "STM32F072_gpio_v1_0",
+    "STM32F072CBUx" => "STM32F072_gpio_v1_0",
+    "STM32F072CBYx" => "STM32F072_gpio_v1_0",
+    "STM32F072R8Tx" => "STM32F072_gpio_v1_0",
+    "STM32F072RBHx" => "STM32F072_gpio_v1_0",
+    "STM32F072RBIx" => "STM32F072_gpio_v1_0",
+    "STM32F072RBTx" => "STM32F072_gpio_v1_0",
+    "STM32F072V8Hx" => "STM32F072_gpio_v1_0",
+    "STM32F072V8Tx" => "STM32F072_gpio_v1_0",
+    "STM32F072VBHx" => "STM32F072_gpio_v1_0",
+    "STM32F072VBTx" => "STM32F072_gpio_v1_0",
+    "STM32F078CBTx" => "STM32F072_gpio_v1_0",
+    "STM32F078CBUx" => "STM32F072_gpio_v1_0",
+    "STM32F078CBYx" => "STM32F072_gpio_v1_0",
+    "STM32F078RBHx" => "STM32F072_gpio_v1_0",
+    "STM32F078RBTx" => "STM32F072_gpio_v1_0",
+    "STM32F078VBHx" => "STM32F072_gpio_v1_0",
+    "STM32F078VBTx" => "STM32F072_gpio_v1_0",
+    "STM32F091CBTx" => "STM32F091_gpio_v1_0",
+    "STM32F091CBUx" => "STM32F091_gpio_v1_0",
+    "STM32F091CCTx" => "STM32F091_gpio_v1_0",
+    "STM32F091CCUx" => "STM32F091_gpio_v1_0",
+    "STM32F091RBTx" => "STM32F091_gpio_v1_0",
+    "STM32F091RCHx" => "STM32F091_gpio_v1_0",
+    "STM32F091RCTx" => "STM32F091_gpio_v1_0",
+    "STM32F091RCYx" => "STM32F091_gpio_v1_0",
+    "STM32F091VBTx" => "STM32F091_gpio_v1_0",
+    "STM32F091VCHx" => "STM32F091_gpio_v1_0",
+    "STM32F091VCTx" => "STM32F091_gpio_v1_0",
+    "STM32F098CCTx" => "STM32F091_gpio_v1_0",
+    "STM32F098CCUx" => "STM32F091_gpio_v1_0",
+    "STM32F098RCHx" => "STM32F091_gpio_v1_0",
+    "STM32F098RCTx" => "STM32F091_gpio_v1_0",
+    "STM32F098RCYx" => "STM32F091_gpio_v1_0",
+    "STM32F098VCHx" => "STM32F091_gpio_v1_0",
+    "STM32F098VCTx" => "STM32F091_gpio_v1_0",
 };
 
-static I2C1_SCL: Map<&str, u8> = phf_map! {
-    "pa9" => 4,
-    "pa11" => 5,
-    "pb6" => 1,
-    "pb8" => 1,
-    "pb10" => 1,
-    "pb13" => 5,
-    "pf1" => 1,
+pub static AF_MAPS: Map<&str, &AfMap> = phf_map! {
+    "STM32F031_gpio_v1_0" => &STM32F031_GPIO_V1_0,
+    "STM32F042_gpio_v1_0" => &STM32F042_GPIO_V1_0,
+    "STM32F051_gpio_v1_0" => &STM32F051_GPIO_V1_0,
+    "STM32F072_gpio_v1_0" => &STM32F072_GPIO_V1_0,
+    "STM32F091_gpio_v1_0" => &STM32F091_GPIO_V1_0,
 };
 
-static I2C1_SDA: Map<&str, u8> = phf_map! {
-    "pa10" => 4,
-    "pa12" => 5,
-    "pb7" => 1,
-    "pb9" => 1,
-    "pb11" => 1,
-    "pb14" => 5,
-    "pf0" => 1,
+static STM32F031_GPIO_V1_0: AfMap = phf_map! {
+    "pa0" => &[("USART1_CTS", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2)],
+    "pa1" => &[("EVENTOUT", 0), ("USART1_DE", 1), ("USART1_RTS", 1), ("TIM2_CH2", 2)],
+    "pa2" => &[("USART1_TX", 1), ("TIM2_CH3", 2)],
+    "pa3" => &[("USART1_RX", 1), ("TIM2_CH4", 2)],
+    "pa4" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART1_CK", 1), ("TIM14_CH1", 4)],
+    "pa5" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("TIM2_CH1", 2), ("TIM2_ETR", 2)],
+    "pa6" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("TIM1_BKIN", 2), ("TIM16_CH1", 5), ("EVENTOUT", 6)],
+    "pa7" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM1_CH1N", 2), ("TIM14_CH1", 4), ("TIM17_CH1", 5), ("EVENTOUT", 6)],
+    "pa8" => &[("RCC_MCO", 0), ("USART1_CK", 1), ("TIM1_CH1", 2), ("EVENTOUT", 3)],
+    "pa9" => &[("USART1_TX", 1), ("TIM1_CH2", 2), ("I2C1_SCL", 4)],
+    "pa10" => &[("TIM17_BKIN", 0), ("USART1_RX", 1), ("TIM1_CH3", 2), ("I2C1_SDA", 4)],
+    "pa11" => &[("EVENTOUT", 0), ("USART1_CTS", 1), ("TIM1_CH4", 2)],
+    "pa12" => &[("EVENTOUT", 0), ("USART1_DE", 1), ("USART1_RTS", 1), ("TIM1_ETR", 2)],
+    "pa13" => &[("SYS_SWDIO", 0), ("IR_OUT", 1)],
+    "pa14" => &[("SYS_SWCLK", 0), ("USART1_TX", 1)],
+    "pa15" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART1_RX", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("EVENTOUT", 3)],
+    "pb0" => &[("EVENTOUT", 0), ("TIM3_CH3", 1), ("TIM1_CH2N", 2)],
+    "pb1" => &[("TIM14_CH1", 0), ("TIM3_CH4", 1), ("TIM1_CH3N", 2)],
+    "pb3" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("EVENTOUT", 1), ("TIM2_CH2", 2)],
+    "pb4" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("EVENTOUT", 2)],
+    "pb5" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM16_BKIN", 2), ("I2C1_SMBA", 3)],
+    "pb6" => &[("USART1_TX", 0), ("I2C1_SCL", 1), ("TIM16_CH1N", 2)],
+    "pb7" => &[("USART1_RX", 0), ("I2C1_SDA", 1), ("TIM17_CH1N", 2)],
+    "pb8" => &[("I2C1_SCL", 1), ("TIM16_CH1", 2)],
+    "pb9" => &[("IR_OUT", 0), ("I2C1_SDA", 1), ("TIM17_CH1", 2)],
+    "pb10" => &[("I2C1_SCL", 1), ("TIM2_CH3", 2)],
+    "pb11" => &[("EVENTOUT", 0), ("I2C1_SDA", 1), ("TIM2_CH4", 2)],
+    "pb12" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("EVENTOUT", 1), ("TIM1_BKIN", 2)],
+    "pb13" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("TIM1_CH1N", 2)],
+    "pb14" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM1_CH2N", 2)],
+    "pb15" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM1_CH3N", 2)],
 };
 
-static I2C2_SCL: Map<&str, u8> = phf_map! {
-    "pa11" => 5,
-    "pb10" => 1,
-    "pb13" => 5,
+static STM32F042_GPIO_V1_0: AfMap = phf_map! {
+    "pa0" => &[("USART2_CTS", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G1_IO1", 3)],
+    "pa1" => &[("EVENTOUT", 0), ("USART2_DE", 1), ("USART2_RTS", 1), ("TIM2_CH2", 2), ("TSC_G1_IO2", 3)],
+    "pa2" => &[("USART2_TX", 1), ("TIM2_CH3", 2), ("TSC_G1_IO3", 3)],
+    "pa3" => &[("USART2_RX", 1), ("TIM2_CH4", 2), ("TSC_G1_IO4", 3)],
+    "pa4" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_CK", 1), ("TSC_G2_IO1", 3), ("TIM14_CH1", 4)],
+    "pa5" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("CEC", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G2_IO2", 3)],
+    "pa6" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("TIM1_BKIN", 2), ("TSC_G2_IO3", 3), ("TIM16_CH1", 5), ("EVENTOUT", 6)],
+    "pa7" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM1_CH1N", 2), ("TSC_G2_IO4", 3), ("TIM14_CH1", 4), ("TIM17_CH1", 5), ("EVENTOUT", 6)],
+    "pa8" => &[("RCC_MCO", 0), ("USART1_CK", 1), ("TIM1_CH1", 2), ("EVENTOUT", 3), ("CRS_SYNC", 4)],
+    "pa9" => &[("USART1_TX", 1), ("TIM1_CH2", 2), ("TSC_G4_IO1", 3), ("I2C1_SCL", 4)],
+    "pa10" => &[("TIM17_BKIN", 0), ("USART1_RX", 1), ("TIM1_CH3", 2), ("TSC_G4_IO2", 3), ("I2C1_SDA", 4)],
+    "pa11" => &[("EVENTOUT", 0), ("USART1_CTS", 1), ("TIM1_CH4", 2), ("TSC_G4_IO3", 3), ("CAN_RX", 4), ("I2C1_SCL", 5)],
+    "pa12" => &[("EVENTOUT", 0), ("USART1_DE", 1), ("USART1_RTS", 1), ("TIM1_ETR", 2), ("TSC_G4_IO4", 3), ("CAN_TX", 4), ("I2C1_SDA", 5)],
+    "pa13" => &[("SYS_SWDIO", 0), ("IR_OUT", 1), ("USB_NOE", 2)],
+    "pa14" => &[("SYS_SWCLK", 0), ("USART2_TX", 1)],
+    "pa15" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_RX", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("EVENTOUT", 3), ("USB_NOE", 5)],
+    "pb0" => &[("EVENTOUT", 0), ("TIM3_CH3", 1), ("TIM1_CH2N", 2), ("TSC_G3_IO2", 3)],
+    "pb1" => &[("TIM14_CH1", 0), ("TIM3_CH4", 1), ("TIM1_CH3N", 2), ("TSC_G3_IO3", 3)],
+    "pb2" => &[("TSC_G3_IO4", 3)],
+    "pb3" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("EVENTOUT", 1), ("TIM2_CH2", 2), ("TSC_G5_IO1", 3)],
+    "pb4" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("EVENTOUT", 2), ("TSC_G5_IO2", 3), ("TIM17_BKIN", 5)],
+    "pb5" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM16_BKIN", 2), ("I2C1_SMBA", 3)],
+    "pb6" => &[("USART1_TX", 0), ("I2C1_SCL", 1), ("TIM16_CH1N", 2), ("TSC_G5_IO3", 3)],
+    "pb7" => &[("USART1_RX", 0), ("I2C1_SDA", 1), ("TIM17_CH1N", 2), ("TSC_G5_IO4", 3)],
+    "pb8" => &[("CEC", 0), ("I2C1_SCL", 1), ("TIM16_CH1", 2), ("TSC_SYNC", 3), ("CAN_RX", 4)],
+    "pb9" => &[("IR_OUT", 0), ("I2C1_SDA", 1), ("TIM17_CH1", 2), ("EVENTOUT", 3), ("CAN_TX", 4), ("SPI2_NSS", 5)],
+    "pb10" => &[("I2C1_SCL", 1), ("TIM2_CH3", 2), ("TSC_SYNC", 3), ("SPI2_SCK", 5)],
+    "pb11" => &[("EVENTOUT", 0), ("I2C1_SDA", 1), ("TIM2_CH4", 2)],
+    "pb12" => &[("SPI2_NSS", 0), ("EVENTOUT", 1), ("TIM1_BKIN", 2)],
+    "pb13" => &[("SPI2_SCK", 0), ("TIM1_CH1N", 2), ("I2C1_SCL", 5)],
+    "pb14" => &[("SPI2_MISO", 0), ("TIM1_CH2N", 2), ("I2C1_SDA", 5)],
+    "pb15" => &[("SPI2_MOSI", 0), ("TIM1_CH3N", 2)],
+    "pf0" => &[("CRS_SYNC", 0), ("I2C1_SDA", 1)],
+    "pf1" => &[("I2C1_SCL", 1)],
 };
 
-static I2C2_SDA: Map<&str, u8> = phf_map! {
-    "pa12" => 5,
-    "pb11" => 1,
-    "pb14" => 5,
+static STM32F051_GPIO_V1_0: AfMap = phf_map! {
+    "pa0" => &[("USART2_CTS", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G1_IO1", 3), ("COMP1_OUT", 7)],
+    "pa1" => &[("EVENTOUT", 0), ("USART2_DE", 1), ("USART2_RTS", 1), ("TIM2_CH2", 2), ("TSC_G1_IO2", 3)],
+    "pa2" => &[("TIM15_CH1", 0), ("USART2_TX", 1), ("TIM2_CH3", 2), ("TSC_G1_IO3", 3), ("COMP2_OUT", 7)],
+    "pa3" => &[("TIM15_CH2", 0), ("USART2_RX", 1), ("TIM2_CH4", 2), ("TSC_G1_IO4", 3)],
+    "pa4" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_CK", 1), ("TSC_G2_IO1", 3), ("TIM14_CH1", 4)],
+    "pa5" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("CEC", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G2_IO2", 3)],
+    "pa6" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("TIM1_BKIN", 2), ("TSC_G2_IO3", 3), ("TIM16_CH1", 5), ("EVENTOUT", 6), ("COMP1_OUT", 7)],
+    "pa7" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM1_CH1N", 2), ("TSC_G2_IO4", 3), ("TIM14_CH1", 4), ("TIM17_CH1", 5), ("EVENTOUT", 6), ("COMP2_OUT", 7)],
+    "pa8" => &[("RCC_MCO", 0), ("USART1_CK", 1), ("TIM1_CH1", 2), ("EVENTOUT", 3)],
+    "pa9" => &[("TIM15_BKIN", 0), ("USART1_TX", 1), ("TIM1_CH2", 2), ("TSC_G4_IO1", 3)],
+    "pa10" => &[("TIM17_BKIN", 0), ("USART1_RX", 1), ("TIM1_CH3", 2), ("TSC_G4_IO2", 3)],
+    "pa11" => &[("EVENTOUT", 0), ("USART1_CTS", 1), ("TIM1_CH4", 2), ("TSC_G4_IO3", 3), ("COMP1_OUT", 7)],
+    "pa12" => &[("EVENTOUT", 0), ("USART1_DE", 1), ("USART1_RTS", 1), ("TIM1_ETR", 2), ("TSC_G4_IO4", 3), ("COMP2_OUT", 7)],
+    "pa13" => &[("SYS_SWDIO", 0), ("IR_OUT", 1)],
+    "pa14" => &[("SYS_SWCLK", 0), ("USART2_TX", 1)],
+    "pa15" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_RX", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("EVENTOUT", 3)],
+    "pb0" => &[("EVENTOUT", 0), ("TIM3_CH3", 1), ("TIM1_CH2N", 2), ("TSC_G3_IO2", 3)],
+    "pb1" => &[("TIM14_CH1", 0), ("TIM3_CH4", 1), ("TIM1_CH3N", 2), ("TSC_G3_IO3", 3)],
+    "pb2" => &[("TSC_G3_IO4", 3)],
+    "pb3" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("EVENTOUT", 1), ("TIM2_CH2", 2), ("TSC_G5_IO1", 3)],
+    "pb4" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("EVENTOUT", 2), ("TSC_G5_IO2", 3)],
+    "pb5" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM16_BKIN", 2), ("I2C1_SMBA", 3)],
+    "pb6" => &[("USART1_TX", 0), ("I2C1_SCL", 1), ("TIM16_CH1N", 2), ("TSC_G5_IO3", 3)],
+    "pb7" => &[("USART1_RX", 0), ("I2C1_SDA", 1), ("TIM17_CH1N", 2), ("TSC_G5_IO4", 3)],
+    "pb8" => &[("CEC", 0), ("I2C1_SCL", 1), ("TIM16_CH1", 2), ("TSC_SYNC", 3)],
+    "pb9" => &[("IR_OUT", 0), ("I2C1_SDA", 1), ("TIM17_CH1", 2), ("EVENTOUT", 3)],
+    "pb10" => &[("CEC", 0), ("I2C2_SCL", 1), ("TIM2_CH3", 2), ("TSC_SYNC", 3)],
+    "pb11" => &[("EVENTOUT", 0), ("I2C2_SDA", 1), ("TIM2_CH4", 2)],
+    "pb12" => &[("SPI2_NSS", 0), ("EVENTOUT", 1), ("TIM1_BKIN", 2)],
+    "pb13" => &[("SPI2_SCK", 0), ("TIM1_CH1N", 2)],
+    "pb14" => &[("SPI2_MISO", 0), ("TIM15_CH1", 1), ("TIM1_CH2N", 2)],
+    "pb15" => &[("SPI2_MOSI", 0), ("TIM15_CH2", 1), ("TIM1_CH3N", 2), ("TIM15_CH1N", 3)],
+    "pd2" => &[("TIM3_ETR", 1)],
 };
 
-static SPI1_MISO: Map<&str, u8> = phf_map! {
-    "pa6" => 0,
-    "pb4" => 0,
-    "pb14" => 0,
-    "pe14" => 1,
+static STM32F072_GPIO_V1_0: AfMap = phf_map! {
+    "pa0" => &[("USART2_CTS", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G1_IO1", 3), ("USART4_TX", 4), ("COMP1_OUT", 7)],
+    "pa1" => &[("EVENTOUT", 0), ("USART2_DE", 1), ("USART2_RTS", 1), ("TIM2_CH2", 2), ("TSC_G1_IO2", 3), ("USART4_RX", 4), ("TIM15_CH1N", 5)],
+    "pa2" => &[("TIM15_CH1", 0), ("USART2_TX", 1), ("TIM2_CH3", 2), ("TSC_G1_IO3", 3), ("COMP2_OUT", 7)],
+    "pa3" => &[("TIM15_CH2", 0), ("USART2_RX", 1), ("TIM2_CH4", 2), ("TSC_G1_IO4", 3)],
+    "pa4" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_CK", 1), ("TSC_G2_IO1", 3), ("TIM14_CH1", 4)],
+    "pa5" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("CEC", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G2_IO2", 3)],
+    "pa6" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("TIM1_BKIN", 2), ("TSC_G2_IO3", 3), ("USART3_CTS", 4), ("TIM16_CH1", 5), ("EVENTOUT", 6), ("COMP1_OUT", 7)],
+    "pa7" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM1_CH1N", 2), ("TSC_G2_IO4", 3), ("TIM14_CH1", 4), ("TIM17_CH1", 5), ("EVENTOUT", 6), ("COMP2_OUT", 7)],
+    "pa8" => &[("RCC_MCO", 0), ("USART1_CK", 1), ("TIM1_CH1", 2), ("EVENTOUT", 3), ("CRS_SYNC", 4)],
+    "pa9" => &[("TIM15_BKIN", 0), ("USART1_TX", 1), ("TIM1_CH2", 2), ("TSC_G4_IO1", 3)],
+    "pa10" => &[("TIM17_BKIN", 0), ("USART1_RX", 1), ("TIM1_CH3", 2), ("TSC_G4_IO2", 3)],
+    "pa11" => &[("EVENTOUT", 0), ("USART1_CTS", 1), ("TIM1_CH4", 2), ("TSC_G4_IO3", 3), ("CAN_RX", 4), ("I2C2_SCL", 5), ("COMP1_OUT", 7)],
+    "pa12" => &[("EVENTOUT", 0), ("USART1_DE", 1), ("USART1_RTS", 1), ("TIM1_ETR", 2), ("TSC_G4_IO4", 3), ("CAN_TX", 4), ("I2C2_SDA", 5), ("COMP2_OUT", 7)],
+    "pa13" => &[("SYS_SWDIO", 0), ("IR_OUT", 1), ("USB_NOE", 2)],
+    "pa14" => &[("SYS_SWCLK", 0), ("USART2_TX", 1)],
+    "pa15" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_RX", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("EVENTOUT", 3), ("USART4_DE", 4), ("USART4_RTS", 4)],
+    "pb0" => &[("EVENTOUT", 0), ("TIM3_CH3", 1), ("TIM1_CH2N", 2), ("TSC_G3_IO2", 3), ("USART3_CK", 4)],
+    "pb1" => &[("TIM14_CH1", 0), ("TIM3_CH4", 1), ("TIM1_CH3N", 2), ("TSC_G3_IO3", 3), ("USART3_DE", 4), ("USART3_RTS", 4)],
+    "pb2" => &[("TSC_G3_IO4", 3)],
+    "pb3" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("EVENTOUT", 1), ("TIM2_CH2", 2), ("TSC_G5_IO1", 3)],
+    "pb4" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("EVENTOUT", 2), ("TSC_G5_IO2", 3), ("TIM17_BKIN", 5)],
+    "pb5" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM16_BKIN", 2), ("I2C1_SMBA", 3)],
+    "pb6" => &[("USART1_TX", 0), ("I2C1_SCL", 1), ("TIM16_CH1N", 2), ("TSC_G5_IO3", 3)],
+    "pb7" => &[("USART1_RX", 0), ("I2C1_SDA", 1), ("TIM17_CH1N", 2), ("TSC_G5_IO4", 3)],
+    "pb8" => &[("CEC", 0), ("I2C1_SCL", 1), ("TIM16_CH1", 2), ("TSC_SYNC", 3), ("CAN_RX", 4)],
+    "pb9" => &[("IR_OUT", 0), ("I2C1_SDA", 1), ("TIM17_CH1", 2), ("EVENTOUT", 3), ("CAN_TX", 4), ("I2S2_WS", 5), ("SPI2_NSS", 5)],
+    "pb10" => &[("CEC", 0), ("I2C2_SCL", 1), ("TIM2_CH3", 2), ("TSC_SYNC", 3), ("USART3_TX", 4), ("I2S2_CK", 5), ("SPI2_SCK", 5)],
+    "pb11" => &[("EVENTOUT", 0), ("I2C2_SDA", 1), ("TIM2_CH4", 2), ("TSC_G6_IO1", 3), ("USART3_RX", 4)],
+    "pb12" => &[("I2S2_WS", 0), ("SPI2_NSS", 0), ("EVENTOUT", 1), ("TIM1_BKIN", 2), ("TSC_G6_IO2", 3), ("USART3_CK", 4), ("TIM15_BKIN", 5)],
+    "pb13" => &[("I2S2_CK", 0), ("SPI2_SCK", 0), ("TIM1_CH1N", 2), ("TSC_G6_IO3", 3), ("USART3_CTS", 4), ("I2C2_SCL", 5)],
+    "pb14" => &[("I2S2_MCK", 0), ("SPI2_MISO", 0), ("TIM15_CH1", 1), ("TIM1_CH2N", 2), ("TSC_G6_IO4", 3), ("USART3_DE", 4), ("USART3_RTS", 4), ("I2C2_SDA", 5)],
+    "pb15" => &[("I2S2_SD", 0), ("SPI2_MOSI", 0), ("TIM15_CH2", 1), ("TIM1_CH3N", 2), ("TIM15_CH1N", 3)],
+    "pc0" => &[("EVENTOUT", 0)],
+    "pc1" => &[("EVENTOUT", 0)],
+    "pc2" => &[("EVENTOUT", 0), ("I2S2_MCK", 1), ("SPI2_MISO", 1)],
+    "pc3" => &[("EVENTOUT", 0), ("I2S2_SD", 1), ("SPI2_MOSI", 1)],
+    "pc4" => &[("EVENTOUT", 0), ("USART3_TX", 1)],
+    "pc5" => &[("TSC_G3_IO1", 0), ("USART3_RX", 1)],
+    "pc6" => &[("TIM3_CH1", 0)],
+    "pc7" => &[("TIM3_CH2", 0)],
+    "pc8" => &[("TIM3_CH3", 0)],
+    "pc9" => &[("TIM3_CH4", 0)],
+    "pc10" => &[("USART4_TX", 0), ("USART3_TX", 1)],
+    "pc11" => &[("USART4_RX", 0), ("USART3_RX", 1)],
+    "pc12" => &[("USART4_CK", 0), ("USART3_CK", 1)],
+    "pd0" => &[("CAN_RX", 0), ("I2S2_WS", 1), ("SPI2_NSS", 1)],
+    "pd1" => &[("CAN_TX", 0), ("I2S2_CK", 1), ("SPI2_SCK", 1)],
+    "pd2" => &[("TIM3_ETR", 0), ("USART3_DE", 1), ("USART3_RTS", 1)],
+    "pd3" => &[("USART2_CTS", 0), ("I2S2_MCK", 1), ("SPI2_MISO", 1)],
+    "pd4" => &[("USART2_DE", 0), ("USART2_RTS", 0), ("I2S2_SD", 1), ("SPI2_MOSI", 1)],
+    "pd5" => &[("USART2_TX", 0)],
+    "pd6" => &[("USART2_RX", 0)],
+    "pd7" => &[("USART2_CK", 0)],
+    "pd8" => &[("USART3_TX", 0)],
+    "pd9" => &[("USART3_RX", 0)],
+    "pd10" => &[("USART3_CK", 0)],
+    "pd11" => &[("USART3_CTS", 0)],
+    "pd12" => &[("USART3_DE", 0), ("USART3_RTS", 0), ("TSC_G8_IO1", 1)],
+    "pd13" => &[("TSC_G8_IO2", 1)],
+    "pd14" => &[("TSC_G8_IO3", 1)],
+    "pd15" => &[("CRS_SYNC", 0), ("TSC_G8_IO4", 1)],
+    "pe0" => &[("TIM16_CH1", 0), ("EVENTOUT", 1)],
+    "pe1" => &[("TIM17_CH1", 0), ("EVENTOUT", 1)],
+    "pe2" => &[("TIM3_ETR", 0), ("TSC_G7_IO1", 1)],
+    "pe3" => &[("TIM3_CH1", 0), ("TSC_G7_IO2", 1)],
+    "pe4" => &[("TIM3_CH2", 0), ("TSC_G7_IO3", 1)],
+    "pe5" => &[("TIM3_CH3", 0), ("TSC_G7_IO4", 1)],
+    "pe6" => &[("TIM3_CH4", 0)],
+    "pe7" => &[("TIM1_ETR", 0)],
+    "pe8" => &[("TIM1_CH1N", 0)],
+    "pe9" => &[("TIM1_CH1", 0)],
+    "pe10" => &[("TIM1_CH2N", 0)],
+    "pe11" => &[("TIM1_CH2", 0)],
+    "pe12" => &[("TIM1_CH3N", 0), ("I2S1_WS", 1), ("SPI1_NSS", 1)],
+    "pe13" => &[("TIM1_CH3", 0), ("I2S1_CK", 1), ("SPI1_SCK", 1)],
+    "pe14" => &[("TIM1_CH4", 0), ("I2S1_MCK", 1), ("SPI1_MISO", 1)],
+    "pe15" => &[("TIM1_BKIN", 0), ("I2S1_SD", 1), ("SPI1_MOSI", 1)],
 };
 
-static SPI1_MOSI: Map<&str, u8> = phf_map! {
-    "pa7" => 0,
-    "pb5" => 0,
-    "pb15" => 0,
-    "pe15" => 1,
-};
-
-static SPI1_SCK: Map<&str, u8> = phf_map! {
-    "pa5" => 0,
-    "pb3" => 0,
-    "pb13" => 0,
-    "pe13" => 1,
-};
-
-static SPI2_MISO: Map<&str, u8> = phf_map! {
-    "pb14" => 0,
-    "pc2" => 1,
-    "pd3" => 1,
-};
-
-static SPI2_MOSI: Map<&str, u8> = phf_map! {
-    "pb15" => 0,
-    "pc3" => 1,
-    "pd4" => 1,
-};
-
-static SPI2_SCK: Map<&str, u8> = phf_map! {
-    "pb10" => 5,
-    "pb13" => 0,
-    "pd1" => 1,
-};
-
-static USART1_RX: Map<&str, u8> = phf_map! {
-    "pa3" => 1,
-    "pa10" => 1,
-    "pa15" => 1,
-    "pb7" => 0,
-};
-
-static USART1_TX: Map<&str, u8> = phf_map! {
-    "pa2" => 1,
-    "pa9" => 1,
-    "pa14" => 1,
-    "pb6" => 0,
-};
-
-static USART2_RX: Map<&str, u8> = phf_map! {
-    "pa3" => 1,
-    "pa15" => 1,
-    "pd6" => 0,
-};
-
-static USART2_TX: Map<&str, u8> = phf_map! {
-    "pa2" => 1,
-    "pa14" => 1,
-    "pd5" => 0,
-};
-
-static USART3_RX: Map<&str, u8> = phf_map! {
-    "pb11" => 4,
-    "pc5" => 1,
-    "pc11" => 1,
-    "pd9" => 0,
-};
-
-static USART3_TX: Map<&str, u8> = phf_map! {
-    "pb10" => 4,
-    "pc4" => 1,
-    "pc10" => 1,
-    "pd8" => 0,
-};
-
-static USART4_RX: Map<&str, u8> = phf_map! {
-    "pa1" => 4,
-    "pc11" => 0,
-    "pe9" => 1,
-};
-
-static USART4_TX: Map<&str, u8> = phf_map! {
-    "pa0" => 4,
-    "pc10" => 0,
-    "pe8" => 1,
-};
-
-static USART5_RX: Map<&str, u8> = phf_map! {
-    "pb4" => 4,
-    "pd2" => 2,
-    "pe11" => 1,
-};
-
-static USART5_TX: Map<&str, u8> = phf_map! {
-    "pb3" => 4,
-    "pc12" => 2,
-    "pe10" => 1,
-};
-
-static USART6_RX: Map<&str, u8> = phf_map! {
-    "pa5" => 5,
-    "pc1" => 2,
-    "pf10" => 1,
-};
-
-static USART6_TX: Map<&str, u8> = phf_map! {
-    "pa4" => 5,
-    "pc0" => 2,
-    "pf9" => 1,
-};
-
-static USART7_RX: Map<&str, u8> = phf_map! {
-    "pc1" => 1,
-    "pc7" => 1,
-    "pf3" => 1,
-};
-
-static USART7_TX: Map<&str, u8> = phf_map! {
-    "pc0" => 1,
-    "pc6" => 1,
-    "pf2" => 1,
-};
-
-static USART8_RX: Map<&str, u8> = phf_map! {
-    "pc3" => 2,
-    "pc9" => 1,
-    "pd14" => 0,
-};
-
-static USART8_TX: Map<&str, u8> = phf_map! {
-    "pc2" => 2,
-    "pc8" => 1,
-    "pd13" => 0,
+static STM32F091_GPIO_V1_0: AfMap = phf_map! {
+    "pa0" => &[("USART2_CTS", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G1_IO1", 3), ("USART4_TX", 4), ("COMP1_OUT", 7)],
+    "pa1" => &[("EVENTOUT", 0), ("USART2_DE", 1), ("USART2_RTS", 1), ("TIM2_CH2", 2), ("TSC_G1_IO2", 3), ("USART4_RX", 4), ("TIM15_CH1N", 5)],
+    "pa2" => &[("TIM15_CH1", 0), ("USART2_TX", 1), ("TIM2_CH3", 2), ("TSC_G1_IO3", 3), ("COMP2_OUT", 7)],
+    "pa3" => &[("TIM15_CH2", 0), ("USART2_RX", 1), ("TIM2_CH4", 2), ("TSC_G1_IO4", 3)],
+    "pa4" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_CK", 1), ("TSC_G2_IO1", 3), ("TIM14_CH1", 4), ("USART6_TX", 5)],
+    "pa5" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("CEC", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("TSC_G2_IO2", 3), ("USART6_RX", 5)],
+    "pa6" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("TIM1_BKIN", 2), ("TSC_G2_IO3", 3), ("USART3_CTS", 4), ("TIM16_CH1", 5), ("EVENTOUT", 6), ("COMP1_OUT", 7)],
+    "pa7" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM1_CH1N", 2), ("TSC_G2_IO4", 3), ("TIM14_CH1", 4), ("TIM17_CH1", 5), ("EVENTOUT", 6), ("COMP2_OUT", 7)],
+    "pa8" => &[("RCC_MCO", 0), ("USART1_CK", 1), ("TIM1_CH1", 2), ("EVENTOUT", 3), ("CRS_SYNC", 4)],
+    "pa9" => &[("TIM15_BKIN", 0), ("USART1_TX", 1), ("TIM1_CH2", 2), ("TSC_G4_IO1", 3)],
+    "pa10" => &[("TIM17_BKIN", 0), ("USART1_RX", 1), ("TIM1_CH3", 2), ("TSC_G4_IO2", 3)],
+    "pa11" => &[("EVENTOUT", 0), ("USART1_CTS", 1), ("TIM1_CH4", 2), ("TSC_G4_IO3", 3), ("CAN_RX", 4), ("I2C2_SCL", 5), ("COMP1_OUT", 7)],
+    "pa12" => &[("EVENTOUT", 0), ("USART1_DE", 1), ("USART1_RTS", 1), ("TIM1_ETR", 2), ("TSC_G4_IO4", 3), ("CAN_TX", 4), ("I2C2_SDA", 5), ("COMP2_OUT", 7)],
+    "pa13" => &[("SYS_SWDIO", 0), ("IR_OUT", 1)],
+    "pa14" => &[("SYS_SWCLK", 0), ("USART2_TX", 1)],
+    "pa15" => &[("I2S1_WS", 0), ("SPI1_NSS", 0), ("USART2_RX", 1), ("TIM2_CH1", 2), ("TIM2_ETR", 2), ("EVENTOUT", 3), ("USART4_DE", 4), ("USART4_RTS", 4)],
+    "pb0" => &[("EVENTOUT", 0), ("TIM3_CH3", 1), ("TIM1_CH2N", 2), ("TSC_G3_IO2", 3), ("USART3_CK", 4)],
+    "pb1" => &[("TIM14_CH1", 0), ("TIM3_CH4", 1), ("TIM1_CH3N", 2), ("TSC_G3_IO3", 3), ("USART3_DE", 4), ("USART3_RTS", 4)],
+    "pb2" => &[("TSC_G3_IO4", 3)],
+    "pb3" => &[("I2S1_CK", 0), ("SPI1_SCK", 0), ("EVENTOUT", 1), ("TIM2_CH2", 2), ("TSC_G5_IO1", 3), ("USART5_TX", 4)],
+    "pb4" => &[("I2S1_MCK", 0), ("SPI1_MISO", 0), ("TIM3_CH1", 1), ("EVENTOUT", 2), ("TSC_G5_IO2", 3), ("USART5_RX", 4), ("TIM17_BKIN", 5)],
+    "pb5" => &[("I2S1_SD", 0), ("SPI1_MOSI", 0), ("TIM3_CH2", 1), ("TIM16_BKIN", 2), ("I2C1_SMBA", 3), ("USART5_CK", 4), ("USART5_DE", 4), ("USART5_RTS", 4)],
+    "pb6" => &[("USART1_TX", 0), ("I2C1_SCL", 1), ("TIM16_CH1N", 2), ("TSC_G5_IO3", 3)],
+    "pb7" => &[("USART1_RX", 0), ("I2C1_SDA", 1), ("TIM17_CH1N", 2), ("TSC_G5_IO4", 3), ("USART4_CTS", 4)],
+    "pb8" => &[("CEC", 0), ("I2C1_SCL", 1), ("TIM16_CH1", 2), ("TSC_SYNC", 3), ("CAN_RX", 4)],
+    "pb9" => &[("IR_OUT", 0), ("I2C1_SDA", 1), ("TIM17_CH1", 2), ("EVENTOUT", 3), ("CAN_TX", 4), ("I2S2_WS", 5), ("SPI2_NSS", 5)],
+    "pb10" => &[("CEC", 0), ("I2C2_SCL", 1), ("TIM2_CH3", 2), ("TSC_SYNC", 3), ("USART3_TX", 4), ("I2S2_CK", 5), ("SPI2_SCK", 5)],
+    "pb11" => &[("EVENTOUT", 0), ("I2C2_SDA", 1), ("TIM2_CH4", 2), ("TSC_G6_IO1", 3), ("USART3_RX", 4)],
+    "pb12" => &[("I2S2_WS", 0), ("SPI2_NSS", 0), ("EVENTOUT", 1), ("TIM1_BKIN", 2), ("TSC_G6_IO2", 3), ("USART3_CK", 4), ("TIM15_BKIN", 5)],
+    "pb13" => &[("I2S2_CK", 0), ("SPI2_SCK", 0), ("TIM1_CH1N", 2), ("TSC_G6_IO3", 3), ("USART3_CTS", 4), ("I2C2_SCL", 5)],
+    "pb14" => &[("I2S2_MCK", 0), ("SPI2_MISO", 0), ("TIM15_CH1", 1), ("TIM1_CH2N", 2), ("TSC_G6_IO4", 3), ("USART3_DE", 4), ("USART3_RTS", 4), ("I2C2_SDA", 5)],
+    "pb15" => &[("I2S2_SD", 0), ("SPI2_MOSI", 0), ("TIM15_CH2", 1), ("TIM1_CH3N", 2), ("TIM15_CH1N", 3)],
+    "pc0" => &[("EVENTOUT", 0), ("USART7_TX", 1), ("USART6_TX", 2)],
+    "pc1" => &[("EVENTOUT", 0), ("USART7_RX", 1), ("USART6_RX", 2)],
+    "pc2" => &[("EVENTOUT", 0), ("I2S2_MCK", 1), ("SPI2_MISO", 1), ("USART8_TX", 2)],
+    "pc3" => &[("EVENTOUT", 0), ("I2S2_SD", 1), ("SPI2_MOSI", 1), ("USART8_RX", 2)],
+    "pc4" => &[("EVENTOUT", 0), ("USART3_TX", 1)],
+    "pc5" => &[("TSC_G3_IO1", 0), ("USART3_RX", 1)],
+    "pc6" => &[("TIM3_CH1", 0), ("USART7_TX", 1)],
+    "pc7" => &[("TIM3_CH2", 0), ("USART7_RX", 1)],
+    "pc8" => &[("TIM3_CH3", 0), ("USART8_TX", 1)],
+    "pc9" => &[("TIM3_CH4", 0), ("USART8_RX", 1)],
+    "pc10" => &[("USART4_TX", 0), ("USART3_TX", 1)],
+    "pc11" => &[("USART4_RX", 0), ("USART3_RX", 1)],
+    "pc12" => &[("USART4_CK", 0), ("USART3_CK", 1), ("USART5_TX", 2)],
+    "pd0" => &[("CAN_RX", 0), ("I2S2_WS", 1), ("SPI2_NSS", 1)],
+    "pd1" => &[("CAN_TX", 0), ("I2S2_CK", 1), ("SPI2_SCK", 1)],
+    "pd2" => &[("TIM3_ETR", 0), ("USART3_DE", 1), ("USART3_RTS", 1), ("USART5_RX", 2)],
+    "pd3" => &[("USART2_CTS", 0), ("I2S2_MCK", 1), ("SPI2_MISO", 1)],
+    "pd4" => &[("USART2_DE", 0), ("USART2_RTS", 0), ("I2S2_SD", 1), ("SPI2_MOSI", 1)],
+    "pd5" => &[("USART2_TX", 0)],
+    "pd6" => &[("USART2_RX", 0)],
+    "pd7" => &[("USART2_CK", 0)],
+    "pd8" => &[("USART3_TX", 0)],
+    "pd9" => &[("USART3_RX", 0)],
+    "pd10" => &[("USART3_CK", 0)],
+    "pd11" => &[("USART3_CTS", 0)],
+    "pd12" => &[("USART3_DE", 0), ("USART3_RTS", 0), ("TSC_G8_IO1", 1), ("USART8_CK", 2), ("USART8_DE", 2), ("USART8_RTS", 2)],
+    "pd13" => &[("USART8_TX", 0), ("TSC_G8_IO2", 1)],
+    "pd14" => &[("USART8_RX", 0), ("TSC_G8_IO3", 1)],
+    "pd15" => &[("CRS_SYNC", 0), ("TSC_G8_IO4", 1), ("USART7_CK", 2), ("USART7_DE", 2), ("USART7_RTS", 2)],
+    "pe0" => &[("TIM16_CH1", 0), ("EVENTOUT", 1)],
+    "pe1" => &[("TIM17_CH1", 0), ("EVENTOUT", 1)],
+    "pe2" => &[("TIM3_ETR", 0), ("TSC_G7_IO1", 1)],
+    "pe3" => &[("TIM3_CH1", 0), ("TSC_G7_IO2", 1)],
+    "pe4" => &[("TIM3_CH2", 0), ("TSC_G7_IO3", 1)],
+    "pe5" => &[("TIM3_CH3", 0), ("TSC_G7_IO4", 1)],
+    "pe6" => &[("TIM3_CH4", 0)],
+    "pe7" => &[("TIM1_ETR", 0), ("USART5_CK", 1), ("USART5_DE", 1), ("USART5_RTS", 1)],
+    "pe8" => &[("TIM1_CH1N", 0), ("USART4_TX", 1)],
+    "pe9" => &[("TIM1_CH1", 0), ("USART4_RX", 1)],
+    "pe10" => &[("TIM1_CH2N", 0), ("USART5_TX", 1)],
+    "pe11" => &[("TIM1_CH2", 0), ("USART5_RX", 1)],
+    "pe12" => &[("TIM1_CH3N", 0), ("I2S1_WS", 1), ("SPI1_NSS", 1)],
+    "pe13" => &[("TIM1_CH3", 0), ("I2S1_CK", 1), ("SPI1_SCK", 1)],
+    "pe14" => &[("TIM1_CH4", 0), ("I2S1_MCK", 1), ("SPI1_MISO", 1)],
+    "pe15" => &[("TIM1_BKIN", 0), ("I2S1_SD", 1), ("SPI1_MOSI", 1)],
+    "pf2" => &[("USART7_TX", 1)],
+    "pf3" => &[("USART7_RX", 1)],
+    "pf9" => &[("USART6_TX", 1)],
+    "pf10" => &[("USART6_RX", 1)],
 };
@@ -0,0 +1,39 @@
+use super::ClockLimits;
+
+// limits from the datasheets, for the fastest parts of each family at VDD = 3.3 V
+
+pub static F0_LIMITS: ClockLimits = ClockLimits {
+    sysclk_max: 48_000_000,
+    hclk_max: 48_000_000,
+    apb1_max: 48_000_000,
+    hse: 4_000_000..=32_000_000,
+    hse_bypass: 1_000_000..=32_000_000,
+    hse_default: 8_000_000,
+    pll_input: 1_000_000..=24_000_000,
+    pll_output: 16_000_000..=48_000_000,
+    flash_wait_states: &[24_000_000, 48_000_000],
+};
+
+pub static F1_LIMITS: ClockLimits = ClockLimits {
+    sysclk_max: 72_000_000,
+    hclk_max: 72_000_000,
+    apb1_max: 36_000_000,
+    hse: 4_000_000..=16_000_000,
+    hse_bypass: 1_000_000..=25_000_000,
+    hse_default: 8_000_000,
+    pll_input: 1_000_000..=25_000_000,
+    pll_output: 16_000_000..=72_000_000,
+    flash_wait_states: &[24_000_000, 48_000_000, 72_000_000],
+};
+
+pub static F3_LIMITS: ClockLimits = ClockLimits {
+    sysclk_max: 72_000_000,
+    hclk_max: 72_000_000,
+    apb1_max: 36_000_000,
+    hse: 4_000_000..=32_000_000,
+    hse_bypass: 1_000_000..=32_000_000,
+    hse_default: 8_000_000,
+    pll_input: 1_000_000..=24_000_000,
+    pll_output: 16_000_000..=72_000_000,
+    flash_wait_states: &[24_000_000, 48_000_000, 72_000_000],
+};
@@ -0,0 +1,482 @@
+// DMA request mapping from the reference manual RM0091, generated by dmaparse.py
+use super::{DmaMap, DmaRemap::*};
+use phf::{phf_map, Map};
+
+pub static DMA_MAPS: Map<&str, &DmaMap> = phf_map! {
+    "STM32F030C6Tx" => &DMA_STM32F030C6TX,
+    "STM32F030C8Tx" => &DMA_STM32F030C8TX,
+    "STM32F030CCTx" => &DMA_STM32F030CCTX,
+    "STM32F030F4Px" => &DMA_STM32F030C6TX,
+    "STM32F030K6Tx" => &DMA_STM32F030C6TX,
+    "STM32F030R8Tx" => &DMA_STM32F030C8TX,
+    "STM32F030RCTx" => &DMA_STM32F030CCTX,
+    "STM32F031C4Tx" => &DMA_STM32F031C4TX,
+    "STM32F031C6Tx" => &DMA_STM32F031C4TX,
+    "STM32F031E6Yx" => &DMA_STM32F031C4TX,
+    "STM32F031F4Px" => &DMA_STM32F031C4TX,
+    "STM32F031F6Px" => &DMA_STM32F031C4TX,
+    "STM32F031G4Ux" => &DMA_STM32F031C4TX,
+    "STM32F031G6Ux" => &DMA_STM32F031C4TX,
+    "STM32F031K4Ux" => &DMA_STM32F031C4TX,
+    "STM32F031K6Tx" => &DMA_STM32F031C4TX,
+    "STM32F031K6Ux" => &DMA_STM32F031C4TX,
+    "STM32F038C6Tx" => &DMA_STM32F031C4TX,
+    "STM32F038E6Yx" => &DMA_STM32F031C4TX,
+    "STM32F038F6Px" => &DMA_STM32F031C4TX,
+    "STM32F038G6Ux" => &DMA_STM32F031C4TX,
+    "STM32F038K6Ux" => &DMA_STM32F031C4TX,
+    "STM32F042C4Tx" => &DMA_STM32F042C4TX,
+    "STM32F042C4Ux" => &DMA_STM32F042C4TX,
+    "STM32F042C6Tx" => &DMA_STM32F042C4TX,
+    "STM32F042C6Ux" => &DMA_STM32F042C4TX,
+    "STM32F042F4Px" => &DMA_STM32F042C4TX,
+    "STM32F042F6Px" => &DMA_STM32F042C4TX,
+    "STM32F042G4Ux" => &DMA_STM32F042C4TX,
+    "STM32F042G6Ux" => &DMA_STM32F042C4TX,
+    "STM32F042K4Tx" => &DMA_STM32F042C4TX,
+    "STM32F042K4Ux" => &DMA_STM32F042C4TX,
+    "STM32F042K6Tx" => &DMA_STM32F042C4TX,
+    "STM32F042K6Ux" => &DMA_STM32F042C4TX,
+    "STM32F042T6Yx" => &DMA_STM32F042C4TX,
+    "STM32F048C6Ux" => &DMA_STM32F042C4TX,
+    "STM32F048G6Ux" => &DMA_STM32F042C4TX,
+    "STM32F048T6Yx" => &DMA_STM32F042C4TX,
+    "STM32F051C4Tx" => &DMA_STM32F051C4TX,
+    "STM32F051C4Ux" => &DMA_STM32F051C4TX,
+    "STM32F051C6Tx" => &DMA_STM32F051C4TX,
+    "STM32F051C6Ux" => &DMA_STM32F051C4TX,
+    "STM32F051C8Tx" => &DMA_STM32F051C4TX,
+    "STM32F051C8Ux" => &DMA_STM32F051C4TX,
+    "STM32F051K4Tx" => &DMA_STM32F051C4TX,
+    "STM32F051K4Ux" => &DMA_STM32F051C4TX,
+    "STM32F051K6Tx" => &DMA_STM32F051C4TX,
+    "STM32F051K6Ux" => &DMA_STM32F051C4TX,
+    "STM32F051K8Tx" => &DMA_STM32F051C4TX,
+    "STM32F051K8Ux" => &DMA_STM32F051C4TX,
+    "STM32F051R4Tx" => &DMA_STM32F051C4TX,
+    "STM32F051R6Tx" => &DMA_STM32F051C4TX,
+    "STM32F051R8Hx" => &DMA_STM32F051C4TX,
+    "STM32F051R8Tx" => &DMA_STM32F051C4TX,
+    "STM32F051T8Yx" => &DMA_STM32F051C4TX,
+    "STM32F058C8Ux" => &DMA_STM32F051C4TX,
+    "STM32F058R8Hx" => &DMA_STM32F051C4TX,
+    "STM32F058R8Tx" => &DMA_STM32F051C4TX,
+    "STM32F058T8Yx" => &DMA_STM32F051C4TX,
+    "STM32F070C6Tx" => &DMA_STM32F070C6TX,
+    "STM32F070CBTx" => &DMA_STM32F070CBTX,
+    "STM32F070F6Px" => &DMA_STM32F070C6TX,
+    "STM32F070RBTx" => &DMA_STM32F070CBTX,
+    "STM32F071C8Tx" => &DMA_STM32F071C8TX,
+    "STM32F071C8Ux" => &DMA_STM32F071C8TX,
+    "STM32F071CBTx" => &DMA_STM32F071C8TX,
+    "STM32F071CBUx" => &DMA_STM32F071C8TX,
+    "STM32F071CBYx" => &DMA_STM32F071C8TX,
+    "STM32F071RBTx" => &DMA_STM32F071C8TX,
+    "STM32F071V8Hx" => &DMA_STM32F071C8TX,
+    "STM32F071V8Tx" => &DMA_STM32F071C8TX,
+    "STM32F071VBHx" => &DMA_STM32F071C8TX,
+    "STM32F071VBTx" => &DMA_STM32F071C8TX,
+    "STM32F072C8Tx" => &DMA_STM32F071C8TX,
+    "STM32F072C8Ux" => &DMA_STM32F071C8TX,
+    "STM32F072CBTx" => &DMA_STM32F071C8TX,
+    "STM32F072CBUx" => &DMA_STM32F071C8TX,
+    "STM32F072CBYx" => &DMA_STM32F071C8TX,
+    "STM32F072R8Tx" => &DMA_STM32F071C8TX,
+    "STM32F072RBHx" => &DMA_STM32F071C8TX,
+    "STM32F072RBIx" => &DMA_STM32F071C8TX,
+    "STM32F072RBTx" => &DMA_STM32F071C8TX,
+    "STM32F072V8Hx" => &DMA_STM32F071C8TX,
+    "STM32F072V8Tx" => &DMA_STM32F071C8TX,
+    "STM32F072VBHx" => &DMA_STM32F071C8TX,
+    "STM32F072VBTx" => &DMA_STM32F071C8TX,
+    "STM32F078CBTx" => &DMA_STM32F071C8TX,
+    "STM32F078CBUx" => &DMA_STM32F071C8TX,
+    "STM32F078CBYx" => &DMA_STM32F071C8TX,
+    "STM32F078RBHx" => &DMA_STM32F071C8TX,
+    "STM32F078RBTx" => &DMA_STM32F071C8TX,
+    "STM32F078VBHx" => &DMA_STM32F071C8TX,
+    "STM32F078VBTx" => &DMA_STM32F071C8TX,
+    "STM32F091CBTx" => &DMA_STM32F091CBTX,
+    "STM32F091CBUx" => &DMA_STM32F091CBTX,
+    "STM32F091CCTx" => &DMA_STM32F091CBTX,
+    "STM32F091CCUx" => &DMA_STM32F091CBTX,
+    "STM32F091RBTx" => &DMA_STM32F091CBTX,
+    "STM32F091RCHx" => &DMA_STM32F091CBTX,
+    "STM32F091RCTx" => &DMA_STM32F091CBTX,
+    "STM32F091RCYx" => &DMA_STM32F091CBTX,
+    "STM32F091VBTx" => &DMA_STM32F091CBTX,
+    "STM32F091VCHx" => &DMA_STM32F091CBTX,
+    "STM32F091VCTx" => &DMA_STM32F091CBTX,
+    "STM32F098CCTx" => &DMA_STM32F091CBTX,
+    "STM32F098CCUx" => &DMA_STM32F091CBTX,
+    "STM32F098RCHx" => &DMA_STM32F091CBTX,
+    "STM32F098RCTx" => &DMA_STM32F091CBTX,
+    "STM32F098RCYx" => &DMA_STM32F091CBTX,
+    "STM32F098VCHx" => &DMA_STM32F091CBTX,
+    "STM32F098VCTx" => &DMA_STM32F091CBTX,
+};
+
+static DMA_STM32F030C6TX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "I2C1_RX" => &[("DMA1_Channel3", Fixed)],
+    "I2C1_TX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH1" => &[("DMA1_Channel2", Fixed)],
+    "TIM1_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH3" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+};
+
+static DMA_STM32F030C8TX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "I2C1_RX" => &[("DMA1_Channel3", Fixed)],
+    "I2C1_TX" => &[("DMA1_Channel2", Fixed)],
+    "I2C2_RX" => &[("DMA1_Channel5", Fixed)],
+    "I2C2_TX" => &[("DMA1_Channel4", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "SPI2_RX" => &[("DMA1_Channel4", Fixed)],
+    "SPI2_TX" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH1" => &[("DMA1_Channel2", Fixed)],
+    "TIM1_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH3" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM6_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM15_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_COM" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_TRIG" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+    "USART2_RX" => &[("DMA1_Channel5", Fixed)],
+    "USART2_TX" => &[("DMA1_Channel4", Fixed)],
+};
+
+static DMA_STM32F030CCTX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Cselr(1)), ("DMA1_Channel2", Cselr(1))],
+    "I2C1_RX" => &[("DMA1_Channel3", Cselr(2))],
+    "I2C1_TX" => &[("DMA1_Channel2", Cselr(2))],
+    "I2C2_RX" => &[("DMA1_Channel5", Cselr(2))],
+    "I2C2_TX" => &[("DMA1_Channel4", Cselr(2))],
+    "SPI1_RX" => &[("DMA1_Channel2", Cselr(3))],
+    "SPI1_TX" => &[("DMA1_Channel3", Cselr(3))],
+    "SPI2_RX" => &[("DMA1_Channel4", Cselr(3))],
+    "SPI2_TX" => &[("DMA1_Channel5", Cselr(3))],
+    "TIM1_CH1" => &[("DMA1_Channel2", Cselr(4))],
+    "TIM1_CH2" => &[("DMA1_Channel3", Cselr(4))],
+    "TIM1_CH3" => &[("DMA1_Channel5", Cselr(4))],
+    "TIM3_CH1" => &[("DMA1_Channel4", Cselr(6))],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Cselr(6))],
+    "TIM6_UP" => &[("DMA1_Channel3", Cselr(1))],
+    "TIM7_UP" => &[("DMA1_Channel4", Cselr(1))],
+    "TIM16_CH1" => &[("DMA1_Channel3", Cselr(7)), ("DMA1_Channel4", Cselr(7))],
+    "TIM16_UP" => &[("DMA1_Channel3", Cselr(7)), ("DMA1_Channel4", Cselr(7))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Cselr(7)), ("DMA1_Channel2", Cselr(7))],
+    "TIM17_UP" => &[("DMA1_Channel1", Cselr(7)), ("DMA1_Channel2", Cselr(7))],
+    "USART1_RX" => &[("DMA1_Channel1", Cselr(8)), ("DMA1_Channel3", Cselr(8)), ("DMA1_Channel5", Cselr(8))],
+    "USART1_TX" => &[("DMA1_Channel2", Cselr(8)), ("DMA1_Channel4", Cselr(8))],
+    "USART2_RX" => &[("DMA1_Channel1", Cselr(9)), ("DMA1_Channel3", Cselr(9)), ("DMA1_Channel5", Cselr(9))],
+    "USART2_TX" => &[("DMA1_Channel2", Cselr(9)), ("DMA1_Channel4", Cselr(9))],
+    "USART3_RX" => &[("DMA1_Channel1", Cselr(10)), ("DMA1_Channel3", Cselr(10)), ("DMA1_Channel5", Cselr(10))],
+    "USART3_TX" => &[("DMA1_Channel2", Cselr(10)), ("DMA1_Channel4", Cselr(10))],
+    "USART4_RX" => &[("DMA1_Channel1", Cselr(11)), ("DMA1_Channel3", Cselr(11)), ("DMA1_Channel5", Cselr(11))],
+    "USART4_TX" => &[("DMA1_Channel2", Cselr(11)), ("DMA1_Channel4", Cselr(11))],
+    "USART5_RX" => &[("DMA1_Channel1", Cselr(12)), ("DMA1_Channel3", Cselr(12)), ("DMA1_Channel5", Cselr(12))],
+    "USART5_TX" => &[("DMA1_Channel2", Cselr(12)), ("DMA1_Channel4", Cselr(12))],
+    "USART6_RX" => &[("DMA1_Channel1", Cselr(13)), ("DMA1_Channel3", Cselr(13)), ("DMA1_Channel5", Cselr(13))],
+    "USART6_TX" => &[("DMA1_Channel2", Cselr(13)), ("DMA1_Channel4", Cselr(13))],
+};
+
+static DMA_STM32F031C4TX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "I2C1_RX" => &[("DMA1_Channel3", Fixed)],
+    "I2C1_TX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH1" => &[("DMA1_Channel2", Fixed)],
+    "TIM1_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH3" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM2_CH3" => &[("DMA1_Channel1", Fixed)],
+    "TIM2_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM2_UP" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+};
+
+static DMA_STM32F042C4TX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "I2C1_RX" => &[("DMA1_Channel3", Fixed)],
+    "I2C1_TX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "SPI2_RX" => &[("DMA1_Channel4", Fixed)],
+    "SPI2_TX" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH1" => &[("DMA1_Channel2", Fixed)],
+    "TIM1_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH3" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM2_CH3" => &[("DMA1_Channel1", Fixed)],
+    "TIM2_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM2_UP" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+    "USART2_RX" => &[("DMA1_Channel5", Fixed)],
+    "USART2_TX" => &[("DMA1_Channel4", Fixed)],
+};
+
+static DMA_STM32F051C4TX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "DAC_CH1" => &[("DMA1_Channel3", Fixed)],
+    "I2C1_RX" => &[("DMA1_Channel3", Fixed)],
+    "I2C1_TX" => &[("DMA1_Channel2", Fixed)],
+    "I2C2_RX" => &[("DMA1_Channel5", Fixed)],
+    "I2C2_TX" => &[("DMA1_Channel4", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "SPI2_RX" => &[("DMA1_Channel4", Fixed)],
+    "SPI2_TX" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH1" => &[("DMA1_Channel2", Fixed)],
+    "TIM1_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH3" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM2_CH3" => &[("DMA1_Channel1", Fixed)],
+    "TIM2_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM2_UP" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM6_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM15_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_COM" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_TRIG" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+    "USART2_RX" => &[("DMA1_Channel5", Fixed)],
+    "USART2_TX" => &[("DMA1_Channel4", Fixed)],
+};
+
+static DMA_STM32F070C6TX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "I2C1_RX" => &[("DMA1_Channel3", Fixed)],
+    "I2C1_TX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH1" => &[("DMA1_Channel2", Fixed)],
+    "TIM1_CH2" => &[("DMA1_Channel3", Fixed)],
+    "TIM1_CH3" => &[("DMA1_Channel5", Fixed)],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+    "USART2_RX" => &[("DMA1_Channel5", Fixed)],
+    "USART2_TX" => &[("DMA1_Channel4", Fixed)],
+};
+
+static DMA_STM32F070CBTX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "I2C1_RX" => &[("DMA1_Channel3", Syscfg("I2C1_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("I2C1_DMA_RMP", true))],
+    "I2C1_TX" => &[("DMA1_Channel2", Syscfg("I2C1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("I2C1_DMA_RMP", true))],
+    "I2C2_RX" => &[("DMA1_Channel5", Fixed)],
+    "I2C2_TX" => &[("DMA1_Channel4", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "SPI2_RX" => &[("DMA1_Channel4", Syscfg("SPI2_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("SPI2_DMA_RMP", true))],
+    "SPI2_TX" => &[("DMA1_Channel5", Syscfg("SPI2_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("SPI2_DMA_RMP", true))],
+    "TIM1_CH1" => &[("DMA1_Channel2", Syscfg("TIM1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM1_DMA_RMP", true))],
+    "TIM1_CH2" => &[("DMA1_Channel3", Syscfg("TIM1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM1_DMA_RMP", true))],
+    "TIM1_CH3" => &[("DMA1_Channel5", Syscfg("TIM1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM1_DMA_RMP", true))],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Syscfg("TIM3_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM3_DMA_RMP", true))],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Syscfg("TIM3_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM3_DMA_RMP", true))],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM6_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM7_UP" => &[("DMA1_Channel4", Fixed)],
+    "TIM15_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_COM" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_TRIG" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true)), ("DMA1_Channel6", Syscfg("TIM16_DMA_RMP2", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true)), ("DMA1_Channel6", Syscfg("TIM16_DMA_RMP2", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true)), ("DMA1_Channel7", Syscfg("TIM17_DMA_RMP2", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true)), ("DMA1_Channel7", Syscfg("TIM17_DMA_RMP2", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+    "USART2_RX" => &[("DMA1_Channel5", Syscfg("USART2_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("USART2_DMA_RMP", true))],
+    "USART2_TX" => &[("DMA1_Channel4", Syscfg("USART2_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("USART2_DMA_RMP", true))],
+    "USART3_RX" => &[("DMA1_Channel3", Syscfg("USART3_DMA_RMP", true)), ("DMA1_Channel6", Syscfg("USART3_DMA_RMP", false))],
+    "USART3_TX" => &[("DMA1_Channel2", Syscfg("USART3_DMA_RMP", true)), ("DMA1_Channel7", Syscfg("USART3_DMA_RMP", false))],
+};
+
+static DMA_STM32F071C8TX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Syscfg("ADC_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("ADC_DMA_RMP", true))],
+    "DAC_CH1" => &[("DMA1_Channel3", Fixed)],
+    "DAC_CH2" => &[("DMA1_Channel4", Fixed)],
+    "I2C1_RX" => &[("DMA1_Channel3", Syscfg("I2C1_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("I2C1_DMA_RMP", true))],
+    "I2C1_TX" => &[("DMA1_Channel2", Syscfg("I2C1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("I2C1_DMA_RMP", true))],
+    "I2C2_RX" => &[("DMA1_Channel5", Fixed)],
+    "I2C2_TX" => &[("DMA1_Channel4", Fixed)],
+    "SPI1_RX" => &[("DMA1_Channel2", Fixed)],
+    "SPI1_TX" => &[("DMA1_Channel3", Fixed)],
+    "SPI2_RX" => &[("DMA1_Channel4", Syscfg("SPI2_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("SPI2_DMA_RMP", true))],
+    "SPI2_TX" => &[("DMA1_Channel5", Syscfg("SPI2_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("SPI2_DMA_RMP", true))],
+    "TIM1_CH1" => &[("DMA1_Channel2", Syscfg("TIM1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM1_DMA_RMP", true))],
+    "TIM1_CH2" => &[("DMA1_Channel3", Syscfg("TIM1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM1_DMA_RMP", true))],
+    "TIM1_CH3" => &[("DMA1_Channel5", Syscfg("TIM1_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM1_DMA_RMP", true))],
+    "TIM1_CH4" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_COM" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_TRIG" => &[("DMA1_Channel4", Fixed)],
+    "TIM1_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM2_CH2" => &[("DMA1_Channel3", Syscfg("TIM2_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("TIM2_DMA_RMP", true))],
+    "TIM2_CH3" => &[("DMA1_Channel1", Fixed)],
+    "TIM2_CH4" => &[("DMA1_Channel4", Syscfg("TIM2_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("TIM2_DMA_RMP", true))],
+    "TIM2_UP" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH1" => &[("DMA1_Channel4", Syscfg("TIM3_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM3_DMA_RMP", true))],
+    "TIM3_CH3" => &[("DMA1_Channel2", Fixed)],
+    "TIM3_CH4" => &[("DMA1_Channel3", Fixed)],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Syscfg("TIM3_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("TIM3_DMA_RMP", true))],
+    "TIM3_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM6_UP" => &[("DMA1_Channel3", Fixed)],
+    "TIM7_UP" => &[("DMA1_Channel4", Fixed)],
+    "TIM15_CH1" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_COM" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_TRIG" => &[("DMA1_Channel5", Fixed)],
+    "TIM15_UP" => &[("DMA1_Channel5", Fixed)],
+    "TIM16_CH1" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true)), ("DMA1_Channel6", Syscfg("TIM16_DMA_RMP2", true))],
+    "TIM16_UP" => &[("DMA1_Channel3", Syscfg("TIM16_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("TIM16_DMA_RMP", true)), ("DMA1_Channel6", Syscfg("TIM16_DMA_RMP2", true))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true)), ("DMA1_Channel7", Syscfg("TIM17_DMA_RMP2", true))],
+    "TIM17_UP" => &[("DMA1_Channel1", Syscfg("TIM17_DMA_RMP", false)), ("DMA1_Channel2", Syscfg("TIM17_DMA_RMP", true)), ("DMA1_Channel7", Syscfg("TIM17_DMA_RMP2", true))],
+    "USART1_RX" => &[("DMA1_Channel3", Syscfg("USART1_RX_DMA_RMP", false)), ("DMA1_Channel5", Syscfg("USART1_RX_DMA_RMP", true))],
+    "USART1_TX" => &[("DMA1_Channel2", Syscfg("USART1_TX_DMA_RMP", false)), ("DMA1_Channel4", Syscfg("USART1_TX_DMA_RMP", true))],
+    "USART2_RX" => &[("DMA1_Channel5", Syscfg("USART2_DMA_RMP", false)), ("DMA1_Channel6", Syscfg("USART2_DMA_RMP", true))],
+    "USART2_TX" => &[("DMA1_Channel4", Syscfg("USART2_DMA_RMP", false)), ("DMA1_Channel7", Syscfg("USART2_DMA_RMP", true))],
+    "USART3_RX" => &[("DMA1_Channel3", Syscfg("USART3_DMA_RMP", true)), ("DMA1_Channel6", Syscfg("USART3_DMA_RMP", false))],
+    "USART3_TX" => &[("DMA1_Channel2", Syscfg("USART3_DMA_RMP", true)), ("DMA1_Channel7", Syscfg("USART3_DMA_RMP", false))],
+};
+
+static DMA_STM32F091CBTX: DmaMap = phf_map! {
+    "ADC" => &[("DMA1_Channel1", Cselr(1)), ("DMA1_Channel2", Cselr(1)), ("DMA2_Channel5", Cselr(1))],
+    "DAC_CH1" => &[("DMA1_Channel3", Cselr(1)), ("DMA2_Channel3", Cselr(1))],
+    "DAC_CH2" => &[("DMA1_Channel4", Cselr(1)), ("DMA2_Channel4", Cselr(1))],
+    "I2C1_RX" => &[("DMA1_Channel3", Cselr(2)), ("DMA1_Channel7", Cselr(2))],
+    "I2C1_TX" => &[("DMA1_Channel2", Cselr(2)), ("DMA1_Channel6", Cselr(2))],
+    "I2C2_RX" => &[("DMA1_Channel5", Cselr(2)), ("DMA2_Channel2", Cselr(2))],
+    "I2C2_TX" => &[("DMA1_Channel4", Cselr(2)), ("DMA2_Channel1", Cselr(2))],
+    "SPI1_RX" => &[("DMA1_Channel2", Cselr(3)), ("DMA2_Channel3", Cselr(3))],
+    "SPI1_TX" => &[("DMA1_Channel3", Cselr(3)), ("DMA2_Channel4", Cselr(3))],
+    "SPI2_RX" => &[("DMA1_Channel4", Cselr(3)), ("DMA1_Channel6", Cselr(3))],
+    "SPI2_TX" => &[("DMA1_Channel5", Cselr(3)), ("DMA1_Channel7", Cselr(3))],
+    "TIM1_CH1" => &[("DMA1_Channel2", Cselr(4)), ("DMA1_Channel6", Cselr(4))],
+    "TIM1_CH2" => &[("DMA1_Channel3", Cselr(4)), ("DMA1_Channel6", Cselr(4))],
+    "TIM1_CH3" => &[("DMA1_Channel5", Cselr(4)), ("DMA1_Channel6", Cselr(4))],
+    "TIM2_CH2" => &[("DMA1_Channel7", Cselr(5))],
+    "TIM2_CH4" => &[("DMA1_Channel4", Cselr(5)), ("DMA1_Channel7", Cselr(5))],
+    "TIM3_CH1" => &[("DMA1_Channel4", Cselr(6)), ("DMA1_Channel6", Cselr(6))],
+    "TIM3_TRIG" => &[("DMA1_Channel4", Cselr(6)), ("DMA1_Channel6", Cselr(6))],
+    "TIM6_UP" => &[("DMA1_Channel3", Cselr(1)), ("DMA2_Channel3", Cselr(1))],
+    "TIM7_UP" => &[("DMA1_Channel4", Cselr(1)), ("DMA2_Channel4", Cselr(1))],
+    "TIM16_CH1" => &[("DMA1_Channel3", Cselr(7)), ("DMA1_Channel4", Cselr(7)), ("DMA1_Channel6", Cselr(7))],
+    "TIM16_UP" => &[("DMA1_Channel3", Cselr(7)), ("DMA1_Channel4", Cselr(7)), ("DMA1_Channel6", Cselr(7))],
+    "TIM17_CH1" => &[("DMA1_Channel1", Cselr(7)), ("DMA1_Channel2", Cselr(7)), ("DMA1_Channel7", Cselr(7))],
+    "TIM17_UP" => &[("DMA1_Channel1", Cselr(7)), ("DMA1_Channel2", Cselr(7)), ("DMA1_Channel7", Cselr(7))],
+    "USART1_RX" => &[("DMA1_Channel1", Cselr(8)), ("DMA1_Channel3", Cselr(8)), ("DMA1_Channel5", Cselr(8)), ("DMA1_Channel6", Cselr(8)), ("DMA2_Channel2", Cselr(8)), ("DMA2_Channel3", Cselr(8))],
+    "USART1_TX" => &[("DMA1_Channel2", Cselr(8)), ("DMA1_Channel4", Cselr(8)), ("DMA1_Channel7", Cselr(8)), ("DMA2_Channel1", Cselr(8)), ("DMA2_Channel4", Cselr(8)), ("DMA2_Channel5", Cselr(8))],
+    "USART2_RX" => &[("DMA1_Channel1", Cselr(9)), ("DMA1_Channel3", Cselr(9)), ("DMA1_Channel5", Cselr(9)), ("DMA1_Channel6", Cselr(9)), ("DMA2_Channel2", Cselr(9)), ("DMA2_Channel3", Cselr(9))],
+    "USART2_TX" => &[("DMA1_Channel2", Cselr(9)), ("DMA1_Channel4", Cselr(9)), ("DMA1_Channel7", Cselr(9)), ("DMA2_Channel1", Cselr(9)), ("DMA2_Channel4", Cselr(9)), ("DMA2_Channel5", Cselr(9))],
+    "USART3_RX" => &[("DMA1_Channel1", Cselr(10)), ("DMA1_Channel3", Cselr(10)), ("DMA1_Channel5", Cselr(10)), ("DMA1_Channel6", Cselr(10)), ("DMA2_Channel2", Cselr(10)), ("DMA2_Channel3", Cselr(10))],
+    "USART3_TX" => &[("DMA1_Channel2", Cselr(10)), ("DMA1_Channel4", Cselr(10)), ("DMA1_Channel7", Cselr(10)), ("DMA2_Channel1", Cselr(10)), ("DMA2_Channel4", Cselr(10)), ("DMA2_Channel5", Cselr(10))],
+    "USART4_RX" => &[("DMA1_Channel1", Cselr(11)), ("DMA1_Channel3", Cselr(11)), ("DMA1_Channel5", Cselr(11)), ("DMA1_Channel6", Cselr(11)), ("DMA2_Channel2", Cselr(11)), ("DMA2_Channel3", Cselr(11))],
+    "USART4_TX" => &[("DMA1_Channel2", Cselr(11)), ("DMA1_Channel4", Cselr(11)), ("DMA1_Channel7", Cselr(11)), ("DMA2_Channel1", Cselr(11)), ("DMA2_Channel4", Cselr(11)), ("DMA2_Channel5", Cselr(11))],
+    "USART5_RX" => &[("DMA1_Channel1", Cselr(12)), ("DMA1_Channel3", Cselr(12)), ("DMA1_Channel5", Cselr(12)), ("DMA1_Channel6", Cselr(12)), ("DMA2_Channel2", Cselr(12)), ("DMA2_Channel3", Cselr(12))],
+    "USART5_TX" => &[("DMA1_Channel2", Cselr(12)), ("DMA1_Channel4", Cselr(12)), ("DMA1_Channel7", Cselr(12)), ("DMA2_Channel1", Cselr(12)), ("DMA2_Channel4", Cselr(12)), ("DMA2_Channel5", Cselr(12))],
+    "USART6_RX" => &[("DMA1_Channel1", Cselr(13)), ("DMA1_Channel3", Cselr(13)), ("DMA1_Channel5", Cselr(13)), ("DMA1_Channel6", Cselr(13)), ("DMA2_Channel2", Cselr(13)), ("DMA2_Channel3", Cselr(13))],
+    "USART6_TX" => &[("DMA1_Channel2", Cselr(13)), ("DMA1_Channel4", Cselr(13)), ("DMA1_Channel7", Cselr(13)), ("DMA2_Channel1", Cselr(13)), ("DMA2_Channel4", Cselr(13)), ("DMA2_Channel5", Cselr(13))],
+    "USART7_RX" => &[("DMA1_Channel1", Cselr(14)), ("DMA1_Channel3", Cselr(14)), ("DMA1_Channel5", Cselr(14)), ("DMA1_Channel6", Cselr(14)), ("DMA2_Channel2", Cselr(14)), ("DMA2_Channel3", Cselr(14))],
+    "USART7_TX" => &[("DMA1_Channel2", Cselr(14)), ("DMA1_Channel4", Cselr(14)), ("DMA1_Channel7", Cselr(14)), ("DMA2_Channel1", Cselr(14)), ("DMA2_Channel4", Cselr(14)), ("DMA2_Channel5", Cselr(14))],
+    "USART8_RX" => &[("DMA1_Channel1", Cselr(15)), ("DMA1_Channel3", Cselr(15)), ("DMA1_Channel5", Cselr(15)), ("DMA1_Channel6", Cselr(15)), ("DMA2_Channel2", Cselr(15)), ("DMA2_Channel3", Cselr(15))],
+    "USART8_TX" => &[("DMA1_Channel2", Cselr(15)), ("DMA1_Channel4", Cselr(15)), ("DMA1_Channel7", Cselr(15)), ("DMA2_Channel1", Cselr(15)), ("DMA2_Channel4", Cselr(15)), ("DMA2_Channel5", Cselr(15))],
+};
@@ -1,19 +1,116 @@
-pub static F0_FEATURES: &[&str] = &[
-    "stm32f030x4",
-    "stm32f030x6",
-    "stm32f030x8",
-    "stm32f030xc",
-    "stm32f031",
-    "stm32f038",
-    "stm32f042",
-    "stm32f048",
-    "stm32f051",
-    "stm32f058",
-    "stm32f070x6",
-    "stm32f070xb",
-    "stm32f071",
-    "stm32f072",
-    "stm32f078",
-    "stm32f091",
-    "stm32f098",
-];
+use phf::{phf_map, Map};
+
+// stm32f0xx-hal feature of every MCU in mem_f0::MEMORY_REGIONS
+pub static F0_FEATURES: Map<&str, &str> = phf_map! {
+    "STM32F030C6Tx" => "stm32f030x6",
+    "STM32F030C8Tx" => "stm32f030x8",
+    "STM32F030CCTx" => "stm32f030xc",
+    "STM32F030F4Px" => "stm32f030x4",
+    "STM32F030K6Tx" => "stm32f030x6",
+    "STM32F030R8Tx" => "stm32f030x8",
+    "STM32F030RCTx" => "stm32f030xc",
+    "STM32F031C4Tx" => "stm32f031",
+    "STM32F031C6Tx" => "stm32f031",
+    "STM32F031E6Yx" => "stm32f031",
+    "STM32F031F4Px" => "stm32f031",
+    "STM32F031F6Px" => "stm32f031",
+    "STM32F031G4Ux" => "stm32f031",
+    "STM32F031G6Ux" => "stm32f031",
+    "STM32F031K4Ux" => "stm32f031",
+    "STM32F031K6Tx" => "stm32f031",
+    "STM32F031K6Ux" => "stm32f031",
+    "STM32F038C6Tx" => "stm32f038",
+    "STM32F038E6Yx" => "stm32f038",
+    "STM32F038F6Px" => "stm32f038",
+    "STM32F038G6Ux" => "stm32f038",
+    "STM32F038K6Ux" => "stm32f038",
+    "STM32F042C4Tx" => "stm32f042",
+    "STM32F042C4Ux" => "stm32f042",
+    "STM32F042C6Tx" => "stm32f042",
+    "STM32F042C6Ux" => "stm32f042",
+    "STM32F042F4Px" => "stm32f042",
+    "STM32F042F6Px" => "stm32f042",
+    "STM32F042G4Ux" => "stm32f042",
+    "STM32F042G6Ux" => "stm32f042",
+    "STM32F042K4Tx" => "stm32f042",
+    "STM32F042K4Ux" => "stm32f042",
+    "STM32F042K6Tx" => "stm32f042",
+    "STM32F042K6Ux" => "stm32f042",
+    "STM32F042T6Yx" => "stm32f042",
+    "STM32F048C6Ux" => "stm32f048",
+    "STM32F048G6Ux" => "stm32f048",
+    "STM32F048T6Yx" => "stm32f048",
+    "STM32F051C4Tx" => "stm32f051",
+    "STM32F051C4Ux" => "stm32f051",
+    "STM32F051C6Tx" => "stm32f051",
+    "STM32F051C6Ux" => "stm32f051",
+    "STM32F051C8Tx" => "stm32f051",
+    "STM32F051C8Ux" => "stm32f051",
+    "STM32F051K4Tx" => "stm32f051",
+    "STM32F051K4Ux" => "stm32f051",
+    "STM32F051K6Tx" => "stm32f051",
+    "STM32F051K6Ux" => "stm32f051",
+    "STM32F051K8Tx" => "stm32f051",
+    "STM32F051K8Ux" => "stm32f051",
+    "STM32F051R4Tx" => "stm32f051",
+    "STM32F051R6Tx" => "stm32f051",
+    "STM32F051R8Hx" => "stm32f051",
+    "STM32F051R8Tx" => "stm32f051",
+    "STM32F051T8Yx" => "stm32f051",
+    "STM32F058C8Ux" => "stm32f058",
+    "STM32F058R8Hx" => "stm32f058",
+    "STM32F058R8Tx" => "stm32f058",
+    "STM32F058T8Yx" => "stm32f058",
+    "STM32F070C6Tx" => "stm32f070x6",
+    "STM32F070CBTx" => "stm32f070xb",
+    "STM32F070F6Px" => "stm32f070x6",
+    "STM32F070RBTx" => "stm32f070xb",
+    "STM32F071C8Tx" => "stm32f071",
+    "STM32F071C8Ux" => "stm32f071",
+    "STM32F071CBTx" => "stm32f071",
+    "STM32F071CBUx" => "stm32f071",
+    "STM32F071CBYx" => "stm32f071",
+    "STM32F071RBTx" => "stm32f071",
+    "STM32F071V8Hx" => "stm32f071",
+    "STM32F071V8Tx" => "stm32f071",
+    "STM32F071VBHx" => "stm32f071",
+    "STM32F071VBTx" => "stm32f071",
+    "STM32F072C8Tx" => "stm32f072",
+    "STM32F072C8Ux" => "stm32f072",
+    "STM32F072CBTx" => "stm32f072",
+    "STM32F072CBUx" => "stm32f072",
+    "STM32F072CBYx" => "stm32f072",
+    "STM32F072R8Tx" => "stm32f072",
+    "STM32F072RBHx" => "stm32f072",
+    "STM32F072RBIx" => "stm32f072",
+    "STM32F072RBTx" => "stm32f072",
+    "STM32F072V8Hx" => "stm32f072",
+    "STM32F072V8Tx" => "stm32f072",
+    "STM32F072VBHx" => "stm32f072",
+    "STM32F072VBTx" => "stm32f072",
+    "STM32F078CBTx" => "stm32f078",
+    "STM32F078CBUx" => "stm32f078",
+    "STM32F078CBYx" => "stm32f078",
+    "STM32F078RBHx" => "stm32f078",
+    "STM32F078RBTx" => "stm32f078",
+    "STM32F078VBHx" => "stm32f078",
+    "STM32F078VBTx" => "stm32f078",
+    "STM32F091CBTx" => "stm32f091",
+    "STM32F091CBUx" => "stm32f091",
+    "STM32F091CCTx" => "stm32f091",
+    "STM32F091CCUx" => "stm32f091",
+    "STM32F091RBTx" => "stm32f091",
+    "STM32F091RCHx" => "stm32f091",
+    "STM32F091RCTx" => "stm32f091",
+    "STM32F091RCYx" => "stm32f091",
+    "STM32F091VBTx" => "stm32f091",
+    "STM32F091VCHx" => "stm32f091",
+    "STM32F091VCTx" => "stm32f091",
+    "STM32F098CCTx" => "stm32f098",
+    "STM32F098CCUx" => "stm32f098",
+    "STM32F098RCHx" => "stm32f098",
+    "STM32F098RCTx" => "stm32f098",
+    "STM32F098RCYx" => "stm32f098",
+    "STM32F098VCHx" => "stm32f098",
+    "STM32F098VCTx" => "stm32f098",
+};
@@ -1,64 +1,392 @@
+use std::ops::RangeInclusive;
+
+use crate::gpio::DEBUG_SIGNALS;
 use crate::*;
-use regex::Regex;
 
+/// Maps a pin to the signals it can carry and their alternate function numbers
+pub type AfMap = phf::Map<&'static str, &'static [(&'static str, u8)]>;
+
+/// Looks up the alternate function number of a signal on a pin,
+/// using the GPIO IP version of the exact MCU
 pub fn get_alternate_function(
-    mcu_family: MCUFamily,
+    config: &Config,
     gpio: &GpioPin,
     peripheral_function: &str,
-) -> u8 {
-    let map = match mcu_family {
-        MCUFamily::STM32F0 => &af_f0::AF_MAP,
-        _ => todo!("other AF_MAPs"),
-    };
-
-    *map.get(peripheral_function)
-        .unwrap_or_else(|| todo!("Alternate functions for {}", peripheral_function))
+) -> anyhow::Result<u8> {
+    get_af_map(config)?
         .get(gpio.register.as_str())
-        .unwrap_or_else(|| {
-            todo!(
-                "Alternate function for {}:{}",
+        .and_then(|signals| {
+            signals
+                .iter()
+                .find(|(signal, _)| *signal == peripheral_function)
+        })
+        .map(|&(_, af)| af)
+        .ok_or_else(|| {
+            anyhow!(
+                "No alternate function for {}:{} on {}",
                 gpio.register,
-                peripheral_function
+                peripheral_function,
+                config.mcu_name
             )
         })
 }
 
-pub struct MemSize {
-    pub flash: usize,
-    pub ram: usize,
+/// The alternate functions of the GPIO IP version of the exact MCU
+fn get_af_map(config: &Config) -> anyhow::Result<&'static AfMap> {
+    let (versions, af_maps) = match config.mcu_family {
+        MCUFamily::STM32F0 => (&af_f0::GPIO_VERSIONS, &af_f0::AF_MAPS),
+        _ => todo!("other AF_MAPS"),
+    };
+
+    let version = versions
+        .get(config.mcu_name.as_str())
+        .ok_or_else(|| anyhow!("No GPIO IP version for {}", config.mcu_name))?;
+
+    af_maps
+        .get(version)
+        .copied()
+        .ok_or_else(|| anyhow!("No alternate functions for {}", version))
+}
+
+/// A named memory region for memory.x, length in bytes
+pub struct MemRegion {
+    pub name: &'static str,
+    pub origin: u32,
+    pub length: u32,
 }
 
-pub fn get_mem_size(config: &Config) -> &MemSize {
+pub fn get_mem_regions(config: &Config) -> anyhow::Result<&'static [MemRegion]> {
     let map = match config.mcu_family {
-        MCUFamily::STM32F0 => &mem_f0::MEMORY_SIZES,
-        _ => todo!("other MEMORY_SIZES"),
+        MCUFamily::STM32F0 => &mem_f0::MEMORY_REGIONS,
+        _ => todo!("other MEMORY_REGIONS"),
     };
 
     map.get(config.mcu_name.as_str())
-        .unwrap_or_else(|| todo!("Unknown MCU {}", config.mcu_name))
+        .copied()
+        .ok_or_else(|| anyhow!("Unknown MCU {}", config.mcu_name))
 }
 
-pub fn get_feature(config: &Config) -> anyhow::Result<&'static str> {
-    let mcu_name = config.mcu_name.to_ascii_lowercase();
+/// The pins of a package, as listed in the CubeMX MCU xml
+pub struct Pinout {
+    pub package: &'static str,
+    pub pins: &'static [Pin],
+}
+
+pub struct Pin {
+    pub position: &'static str,
+    pub name: &'static str,
+    pub pin_type: PinType,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PinType {
+    Io,
+    Power,
+    Reset,
+    Boot,
+}
+
+pub fn get_pinout(config: &Config) -> Option<&'static Pinout> {
+    let map = match config.mcu_family {
+        MCUFamily::STM32F0 => &pinout_f0::PINOUTS,
+        _ => todo!("other PINOUTS"),
+    };
+
+    map.get(config.mcu_ref.as_str())
+}
+
+/// Maps a DMA request to the channels that can serve it
+pub type DmaMap = phf::Map<&'static str, &'static [(&'static str, DmaRemap)]>;
+
+/// What it takes to route a DMA request to a channel
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DmaRemap {
+    /// the request is always connected to the channel
+    Fixed,
+    /// SYSCFG_CFGR1 remap bit that has to be cleared (false) or set (true)
+    Syscfg(&'static str, bool),
+    /// request selection of the channel in DMAx_CSELR
+    Cselr(u8),
+}
+
+pub fn get_dma_channels(
+    config: &Config,
+    request: &str,
+) -> anyhow::Result<&'static [(&'static str, DmaRemap)]> {
+    let maps = match config.mcu_family {
+        MCUFamily::STM32F0 => &dma_f0::DMA_MAPS,
+        _ => todo!("other DMA_MAPS"),
+    };
+
+    let map = maps
+        .get(config.mcu_name.as_str())
+        .ok_or_else(|| anyhow!("No DMA requests known for {}", config.mcu_name))?;
+
+    map.get(request)
+        .copied()
+        .ok_or_else(|| anyhow!("{} has no DMA request {}", config.mcu_name, request))
+}
+
+/// Whether the MCU has the DAC channel, every channel comes with a DMA request
+pub fn has_dac_channel(config: &Config, channel: u8) -> bool {
+    get_dma_channels(config, &f!("DAC_CH{channel}")).is_ok()
+}
+
+/// An entry of the interrupt vector table
+pub struct Vector {
+    pub number: u8,
+    /// name in the ioc file and the C headers, e.g. USART3_4_IRQn
+    pub cube_name: &'static str,
+    /// variant of the PAC Interrupt enum, e.g. USART3_4
+    pub pac_name: &'static str,
+}
+
+pub fn get_vectors(config: &Config) -> anyhow::Result<&'static [Vector]> {
+    let map = match config.mcu_family {
+        MCUFamily::STM32F0 => &vectors_f0::VECTORS,
+        _ => todo!("other VECTORS"),
+    };
+
+    map.get(config.mcu_name.as_str())
+        .copied()
+        .ok_or_else(|| anyhow!("No interrupt vectors known for {}", config.mcu_name))
+}
+
+pub fn get_vector<'a>(vectors: &'a [Vector], cube_name: &str) -> Option<&'a Vector> {
+    vectors.iter().find(|vector| vector.cube_name == cube_name)
+}
 
+/// The debug signal a pin carries after reset, e.g. SYS_SWDIO on pa13
+pub fn get_reset_debug_signal(config: &Config, register: &str) -> Option<&'static str> {
+    // the debug signals are on AF0 after reset
+    get_af_map(config)
+        .ok()?
+        .get(register)?
+        .iter()
+        .find(|&&(signal, af)| af == 0 && DEBUG_SIGNALS.contains(&signal))
+        .map(|&(signal, _)| signal)
+}
+
+/// The CubeMX name of the interrupt vector shared by an EXTI line
+pub fn get_exti_vector(mcu_family: MCUFamily, line: u8) -> &'static str {
+    match mcu_family {
+        MCUFamily::STM32F0 => match line {
+            0..=1 => "EXTI0_1_IRQn",
+            2..=3 => "EXTI2_3_IRQn",
+            _ => "EXTI4_15_IRQn",
+        },
+        _ => todo!("other EXTI vectors"),
+    }
+}
+
+/// Clock frequency limits of a family, in Hz
+pub struct ClockLimits {
+    pub sysclk_max: u32,
+    pub hclk_max: u32,
+    pub apb1_max: u32,
+    /// crystal or ceramic resonator on OSC_IN/OSC_OUT
+    pub hse: RangeInclusive<u32>,
+    /// external clock on OSC_IN
+    pub hse_bypass: RangeInclusive<u32>,
+    /// CubeMX's HSE_VALUE if the ioc file doesn't list it
+    pub hse_default: u32,
+    pub pll_input: RangeInclusive<u32>,
+    pub pll_output: RangeInclusive<u32>,
+    /// highest HCLK for 0, 1, 2, .. flash wait states
+    pub flash_wait_states: &'static [u32],
+}
+
+pub fn get_clock_limits(mcu_family: MCUFamily) -> &'static ClockLimits {
+    match mcu_family {
+        MCUFamily::STM32F0 => &clocks::F0_LIMITS,
+        MCUFamily::STM32F1 => &clocks::F1_LIMITS,
+        MCUFamily::STM32F3 => &clocks::F3_LIMITS,
+        _ => todo!("other clock limits"),
+    }
+}
+
+/// Whether the PLL gets HSI through PREDIV instead of the fixed HSI/2
+pub fn has_pll_hsi_prediv(mcu_family: MCUFamily, mcu_name: &str) -> bool {
+    match mcu_family {
+        // STM32F04x, STM32F07x, STM32F09x and STM32F030xC
+        MCUFamily::STM32F0 => matches!(
+            features::F0_FEATURES.get(mcu_name),
+            Some(&"stm32f030xc")
+                | Some(&"stm32f042")
+                | Some(&"stm32f048")
+                | Some(&"stm32f070x6")
+                | Some(&"stm32f070xb")
+                | Some(&"stm32f071")
+                | Some(&"stm32f072")
+                | Some(&"stm32f078")
+                | Some(&"stm32f091")
+                | Some(&"stm32f098")
+        ),
+        _ => todo!("other PLL sources"),
+    }
+}
+
+/// Whether the ADC can convert VBAT/2, the value line parts have no VBAT pin
+pub fn has_adc_vbat(mcu_family: MCUFamily, mcu_name: &str) -> bool {
+    match mcu_family {
+        // everything but STM32F030 and STM32F070
+        MCUFamily::STM32F0 => !matches!(
+            features::F0_FEATURES.get(mcu_name),
+            Some(&"stm32f030x4")
+                | Some(&"stm32f030x6")
+                | Some(&"stm32f030x8")
+                | Some(&"stm32f030xc")
+                | Some(&"stm32f070x6")
+                | Some(&"stm32f070xb")
+                | None
+        ),
+        _ => todo!("other ADC channels"),
+    }
+}
+
+pub fn get_feature(config: &Config) -> anyhow::Result<&'static str> {
     let features = match config.mcu_family {
-        MCUFamily::STM32F0 => features::F0_FEATURES,
+        MCUFamily::STM32F0 => &features::F0_FEATURES,
         _ => todo!("More features"),
     };
 
-    for feature in features {
-        // x can be any word character
-        // "stm32f030x4" -> Regex::new(r"^stm32f030\w4")
-        let regex = Regex::new(&("^".to_string() + &feature.replace("x", r"\w"))).unwrap();
+    features
+        .get(config.mcu_name.as_str())
+        .copied()
+        .ok_or_else(|| {
+            let suggestions = closest_names(features.keys().copied(), &config.mcu_name);
+            anyhow!(
+                "no feature for {}, closest known parts: {}",
+                config.mcu_name,
+                suggestions.join(", ")
+            )
+        })
+}
+
+/// Resolves the exact MCU from Mcu.UserName and Mcu.Name.
+/// Mcu.Name can describe a range of MCUs like STM32F042C(4-6)Tx,
+/// Mcu.UserName can be an ordering code like STM32F042C6T6
+pub fn resolve_mcu_name(
+    mcu_family: MCUFamily,
+    user_name: Option<&str>,
+    mcu_ref: &str,
+) -> anyhow::Result<&'static str> {
+    let known = match mcu_family {
+        MCUFamily::STM32F0 => &mem_f0::MEMORY_REGIONS,
+        _ => todo!("other MEMORY_REGIONS"),
+    };
+
+    if let Some((&name, _)) = user_name.and_then(|name| known.get_entry(name)) {
+        return Ok(name);
+    }
+
+    // the MCUs described by Mcu.Name that are in the database
+    let candidates: Vec<&'static str> = expand_mcu_range(mcu_ref)
+        .iter()
+        .filter_map(|name| known.get_entry(name.as_str()).map(|(&name, _)| name))
+        .collect();
+
+    let matches: Vec<&'static str> = match user_name {
+        Some(user_name) => {
+            let in_range: Vec<_> = candidates
+                .iter()
+                .copied()
+                .filter(|name| is_same_part(name, user_name))
+                .collect();
+            if in_range.is_empty() {
+                known
+                    .keys()
+                    .copied()
+                    .filter(|name| is_same_part(name, user_name))
+                    .collect()
+            } else {
+                in_range
+            }
+        }
+        None => candidates.clone(),
+    };
+
+    match matches[..] {
+        [name] => Ok(name),
+        [] if !candidates.is_empty() => bail!(
+            "Mcu.UserName={} is not one of {}",
+            user_name.unwrap_or_default(),
+            candidates.join(", ")
+        ),
+        [] => {
+            let name = user_name.unwrap_or(mcu_ref);
+            let suggestions = closest_names(known.keys().copied(), name);
+            bail!(
+                "Unknown MCU {}, closest known parts: {}",
+                name,
+                suggestions.join(", ")
+            )
+        }
+        _ => bail!(
+            "{} is ambiguous, set Mcu.UserName to one of {}",
+            mcu_ref,
+            matches.join(", ")
+        ),
+    }
+}
+
+/// Expands CubeMX range notation, "STM32F051C(4-6-8)Tx" -> STM32F051C4Tx, STM32F051C6Tx, STM32F051C8Tx
+fn expand_mcu_range(name: &str) -> Vec<String> {
+    match (name.find('('), name.find(')')) {
+        (Some(start), Some(end)) if start < end => name[start + 1..end]
+            .split('-')
+            .flat_map(|variant| {
+                expand_mcu_range(&f!("{}{}{}", &name[..start], variant, &name[end + 1..]))
+            })
+            .collect(),
+        _ => vec![name.to_string()],
+    }
+}
+
+/// Compares a database name like STM32F042C6Tx, where the last letter is a placeholder,
+/// with a name or ordering code like STM32F042C6T6 or STM32F042C6T6TR,
+/// the temperature range and packing suffix don't matter
+fn is_same_part(known: &str, name: &str) -> bool {
+    let prefix_len = known.len() - 1;
+    name.is_ascii()
+        && name.len() >= known.len()
+        && known[..prefix_len].eq_ignore_ascii_case(&name[..prefix_len])
+}
+
+/// The three names with the smallest edit distance to the given name
+fn closest_names<'a>(names: impl Iterator<Item = &'a str>, name: &str) -> Vec<&'a str> {
+    let name = name.to_ascii_uppercase();
+    let mut names: Vec<_> = names
+        .map(|known| (edit_distance(&known.to_ascii_uppercase(), &name), known))
+        .collect();
+    names.sort();
+    names.into_iter().take(3).map(|(_, known)| known).collect()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
 
-        if regex.is_match(&mcu_name) {
-            return Ok(feature);
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = diagonal + if a_char == b_char { 0 } else { 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
         }
     }
 
-    bail!("no feature for {}", mcu_name)
+    row[b.len()]
 }
 
 mod af_f0;
+mod clocks;
+mod dma_f0;
 mod features;
+#[rustfmt::skip]
 mod mem_f0;
+#[rustfmt::skip]
+mod pinout_f0;
+#[rustfmt::skip]
+mod vectors_f0;
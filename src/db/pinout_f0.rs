@@ -0,0 +1,775 @@
+use super::{Pin, PinType::*, Pinout};
+use phf::{phf_map, Map};
+
+pub static PINOUTS: Map<&str, Pinout> = phf_map! {
+    "STM32F030C6Tx" => Pinout {package: "LQFP48", pins: STM32F030C6TX},
+    "STM32F030F4Px" => Pinout {package: "TSSOP20", pins: STM32F030F4PX},
+    "STM32F030K6Tx" => Pinout {package: "LQFP32", pins: STM32F030K6TX},
+    "STM32F030R8Tx" => Pinout {package: "LQFP64", pins: STM32F030R8TX},
+    "STM32F031C(4-6)Tx" => Pinout {package: "LQFP48", pins: STM32F031C_4_6_TX},
+    "STM32F031G(4-6)Ux" => Pinout {package: "UFQFPN28", pins: STM32F031G_4_6_UX},
+    "STM32F042C(4-6)Tx" => Pinout {package: "LQFP48", pins: STM32F042C_4_6_TX},
+    "STM32F042C(4-6)Ux" => Pinout {package: "UFQFPN48", pins: STM32F042C_4_6_TX},
+    "STM32F042F(4-6)Px" => Pinout {package: "TSSOP20", pins: STM32F042F_4_6_PX},
+    "STM32F042G(4-6)Ux" => Pinout {package: "UFQFPN28", pins: STM32F042G_4_6_UX},
+    "STM32F042K(4-6)Tx" => Pinout {package: "LQFP32", pins: STM32F042K_4_6_TX},
+    "STM32F042K(4-6)Ux" => Pinout {package: "UFQFPN32", pins: STM32F042K_4_6_TX},
+    "STM32F051R(4-6-8)Tx" => Pinout {package: "LQFP64", pins: STM32F051R_4_6_8_TX},
+    "STM32F070CBTx" => Pinout {package: "LQFP48", pins: STM32F070CBTX},
+    "STM32F070RBTx" => Pinout {package: "LQFP64", pins: STM32F070RBTX},
+    "STM32F071RBTx" => Pinout {package: "LQFP64", pins: STM32F071RBTX},
+    "STM32F071V(8-B)Tx" => Pinout {package: "LQFP100", pins: STM32F071V_8_B_TX},
+};
+
+static STM32F030C6TX: &[Pin] = &[
+    Pin {position: "1", name: "VDD", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "VSSA", pin_type: Power},
+    Pin {position: "9", name: "VDDA", pin_type: Power},
+    Pin {position: "10", name: "PA0", pin_type: Io},
+    Pin {position: "11", name: "PA1", pin_type: Io},
+    Pin {position: "12", name: "PA2", pin_type: Io},
+    Pin {position: "13", name: "PA3", pin_type: Io},
+    Pin {position: "14", name: "PA4", pin_type: Io},
+    Pin {position: "15", name: "PA5", pin_type: Io},
+    Pin {position: "16", name: "PA6", pin_type: Io},
+    Pin {position: "17", name: "PA7", pin_type: Io},
+    Pin {position: "18", name: "PB0", pin_type: Io},
+    Pin {position: "19", name: "PB1", pin_type: Io},
+    Pin {position: "20", name: "PB2", pin_type: Io},
+    Pin {position: "21", name: "PB10", pin_type: Io},
+    Pin {position: "22", name: "PB11", pin_type: Io},
+    Pin {position: "23", name: "VSS", pin_type: Power},
+    Pin {position: "24", name: "VDD", pin_type: Power},
+    Pin {position: "25", name: "PB12", pin_type: Io},
+    Pin {position: "26", name: "PB13", pin_type: Io},
+    Pin {position: "27", name: "PB14", pin_type: Io},
+    Pin {position: "28", name: "PB15", pin_type: Io},
+    Pin {position: "29", name: "PA8", pin_type: Io},
+    Pin {position: "30", name: "PA9", pin_type: Io},
+    Pin {position: "31", name: "PA10", pin_type: Io},
+    Pin {position: "32", name: "PA11", pin_type: Io},
+    Pin {position: "33", name: "PA12", pin_type: Io},
+    Pin {position: "34", name: "PA13", pin_type: Io},
+    Pin {position: "35", name: "PF6", pin_type: Io},
+    Pin {position: "36", name: "PF7", pin_type: Io},
+    Pin {position: "37", name: "PA14", pin_type: Io},
+    Pin {position: "38", name: "PA15", pin_type: Io},
+    Pin {position: "39", name: "PB3", pin_type: Io},
+    Pin {position: "40", name: "PB4", pin_type: Io},
+    Pin {position: "41", name: "PB5", pin_type: Io},
+    Pin {position: "42", name: "PB6", pin_type: Io},
+    Pin {position: "43", name: "PB7", pin_type: Io},
+    Pin {position: "44", name: "BOOT0", pin_type: Boot},
+    Pin {position: "45", name: "PB8", pin_type: Io},
+    Pin {position: "46", name: "PB9", pin_type: Io},
+    Pin {position: "47", name: "VSS", pin_type: Power},
+    Pin {position: "48", name: "VDD", pin_type: Power},
+];
+
+static STM32F030F4PX: &[Pin] = &[
+    Pin {position: "1", name: "BOOT0", pin_type: Boot},
+    Pin {position: "2", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "3", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "4", name: "NRST", pin_type: Reset},
+    Pin {position: "5", name: "VDDA", pin_type: Power},
+    Pin {position: "6", name: "PA0", pin_type: Io},
+    Pin {position: "7", name: "PA1", pin_type: Io},
+    Pin {position: "8", name: "PA2", pin_type: Io},
+    Pin {position: "9", name: "PA3", pin_type: Io},
+    Pin {position: "10", name: "PA4", pin_type: Io},
+    Pin {position: "11", name: "PA5", pin_type: Io},
+    Pin {position: "12", name: "PA6", pin_type: Io},
+    Pin {position: "13", name: "PA7", pin_type: Io},
+    Pin {position: "14", name: "PB1", pin_type: Io},
+    Pin {position: "15", name: "VSS", pin_type: Power},
+    Pin {position: "16", name: "VDD", pin_type: Power},
+    Pin {position: "17", name: "PA9", pin_type: Io},
+    Pin {position: "18", name: "PA10", pin_type: Io},
+    Pin {position: "19", name: "PA13", pin_type: Io},
+    Pin {position: "20", name: "PA14", pin_type: Io},
+];
+
+static STM32F030K6TX: &[Pin] = &[
+    Pin {position: "1", name: "VDD", pin_type: Power},
+    Pin {position: "2", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "3", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "4", name: "NRST", pin_type: Reset},
+    Pin {position: "5", name: "VDDA", pin_type: Power},
+    Pin {position: "6", name: "PA0", pin_type: Io},
+    Pin {position: "7", name: "PA1", pin_type: Io},
+    Pin {position: "8", name: "PA2", pin_type: Io},
+    Pin {position: "9", name: "PA3", pin_type: Io},
+    Pin {position: "10", name: "PA4", pin_type: Io},
+    Pin {position: "11", name: "PA5", pin_type: Io},
+    Pin {position: "12", name: "PA6", pin_type: Io},
+    Pin {position: "13", name: "PA7", pin_type: Io},
+    Pin {position: "14", name: "PB0", pin_type: Io},
+    Pin {position: "15", name: "PB1", pin_type: Io},
+    Pin {position: "16", name: "VSS", pin_type: Power},
+    Pin {position: "17", name: "VDD", pin_type: Power},
+    Pin {position: "18", name: "PA8", pin_type: Io},
+    Pin {position: "19", name: "PA9", pin_type: Io},
+    Pin {position: "20", name: "PA10", pin_type: Io},
+    Pin {position: "21", name: "PA11", pin_type: Io},
+    Pin {position: "22", name: "PA12", pin_type: Io},
+    Pin {position: "23", name: "PA13", pin_type: Io},
+    Pin {position: "24", name: "PA14", pin_type: Io},
+    Pin {position: "25", name: "PA15", pin_type: Io},
+    Pin {position: "26", name: "PB3", pin_type: Io},
+    Pin {position: "27", name: "PB4", pin_type: Io},
+    Pin {position: "28", name: "PB5", pin_type: Io},
+    Pin {position: "29", name: "PB6", pin_type: Io},
+    Pin {position: "30", name: "PB7", pin_type: Io},
+    Pin {position: "31", name: "BOOT0", pin_type: Boot},
+    Pin {position: "32", name: "VSS", pin_type: Power},
+];
+
+static STM32F030R8TX: &[Pin] = &[
+    Pin {position: "1", name: "VDD", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "PC0", pin_type: Io},
+    Pin {position: "9", name: "PC1", pin_type: Io},
+    Pin {position: "10", name: "PC2", pin_type: Io},
+    Pin {position: "11", name: "PC3", pin_type: Io},
+    Pin {position: "12", name: "VSSA", pin_type: Power},
+    Pin {position: "13", name: "VDDA", pin_type: Power},
+    Pin {position: "14", name: "PA0", pin_type: Io},
+    Pin {position: "15", name: "PA1", pin_type: Io},
+    Pin {position: "16", name: "PA2", pin_type: Io},
+    Pin {position: "17", name: "PA3", pin_type: Io},
+    Pin {position: "18", name: "PF4", pin_type: Io},
+    Pin {position: "19", name: "PF5", pin_type: Io},
+    Pin {position: "20", name: "PA4", pin_type: Io},
+    Pin {position: "21", name: "PA5", pin_type: Io},
+    Pin {position: "22", name: "PA6", pin_type: Io},
+    Pin {position: "23", name: "PA7", pin_type: Io},
+    Pin {position: "24", name: "PC4", pin_type: Io},
+    Pin {position: "25", name: "PC5", pin_type: Io},
+    Pin {position: "26", name: "PB0", pin_type: Io},
+    Pin {position: "27", name: "PB1", pin_type: Io},
+    Pin {position: "28", name: "PB2", pin_type: Io},
+    Pin {position: "29", name: "PB10", pin_type: Io},
+    Pin {position: "30", name: "PB11", pin_type: Io},
+    Pin {position: "31", name: "VSS", pin_type: Power},
+    Pin {position: "32", name: "VDD", pin_type: Power},
+    Pin {position: "33", name: "PB12", pin_type: Io},
+    Pin {position: "34", name: "PB13", pin_type: Io},
+    Pin {position: "35", name: "PB14", pin_type: Io},
+    Pin {position: "36", name: "PB15", pin_type: Io},
+    Pin {position: "37", name: "PC6", pin_type: Io},
+    Pin {position: "38", name: "PC7", pin_type: Io},
+    Pin {position: "39", name: "PC8", pin_type: Io},
+    Pin {position: "40", name: "PC9", pin_type: Io},
+    Pin {position: "41", name: "PA8", pin_type: Io},
+    Pin {position: "42", name: "PA9", pin_type: Io},
+    Pin {position: "43", name: "PA10", pin_type: Io},
+    Pin {position: "44", name: "PA11", pin_type: Io},
+    Pin {position: "45", name: "PA12", pin_type: Io},
+    Pin {position: "46", name: "PA13", pin_type: Io},
+    Pin {position: "47", name: "PF6", pin_type: Io},
+    Pin {position: "48", name: "PF7", pin_type: Io},
+    Pin {position: "49", name: "PA14", pin_type: Io},
+    Pin {position: "50", name: "PA15", pin_type: Io},
+    Pin {position: "51", name: "PC10", pin_type: Io},
+    Pin {position: "52", name: "PC11", pin_type: Io},
+    Pin {position: "53", name: "PC12", pin_type: Io},
+    Pin {position: "54", name: "PD2", pin_type: Io},
+    Pin {position: "55", name: "PB3", pin_type: Io},
+    Pin {position: "56", name: "PB4", pin_type: Io},
+    Pin {position: "57", name: "PB5", pin_type: Io},
+    Pin {position: "58", name: "PB6", pin_type: Io},
+    Pin {position: "59", name: "PB7", pin_type: Io},
+    Pin {position: "60", name: "BOOT0", pin_type: Boot},
+    Pin {position: "61", name: "PB8", pin_type: Io},
+    Pin {position: "62", name: "PB9", pin_type: Io},
+    Pin {position: "63", name: "VSS", pin_type: Power},
+    Pin {position: "64", name: "VDD", pin_type: Power},
+];
+
+static STM32F031C_4_6_TX: &[Pin] = &[
+    Pin {position: "1", name: "VBAT", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "VSSA", pin_type: Power},
+    Pin {position: "9", name: "VDDA", pin_type: Power},
+    Pin {position: "10", name: "PA0", pin_type: Io},
+    Pin {position: "11", name: "PA1", pin_type: Io},
+    Pin {position: "12", name: "PA2", pin_type: Io},
+    Pin {position: "13", name: "PA3", pin_type: Io},
+    Pin {position: "14", name: "PA4", pin_type: Io},
+    Pin {position: "15", name: "PA5", pin_type: Io},
+    Pin {position: "16", name: "PA6", pin_type: Io},
+    Pin {position: "17", name: "PA7", pin_type: Io},
+    Pin {position: "18", name: "PB0", pin_type: Io},
+    Pin {position: "19", name: "PB1", pin_type: Io},
+    Pin {position: "20", name: "PB2", pin_type: Io},
+    Pin {position: "21", name: "PB10", pin_type: Io},
+    Pin {position: "22", name: "PB11", pin_type: Io},
+    Pin {position: "23", name: "VSS", pin_type: Power},
+    Pin {position: "24", name: "VDD", pin_type: Power},
+    Pin {position: "25", name: "PB12", pin_type: Io},
+    Pin {position: "26", name: "PB13", pin_type: Io},
+    Pin {position: "27", name: "PB14", pin_type: Io},
+    Pin {position: "28", name: "PB15", pin_type: Io},
+    Pin {position: "29", name: "PA8", pin_type: Io},
+    Pin {position: "30", name: "PA9", pin_type: Io},
+    Pin {position: "31", name: "PA10", pin_type: Io},
+    Pin {position: "32", name: "PA11", pin_type: Io},
+    Pin {position: "33", name: "PA12", pin_type: Io},
+    Pin {position: "34", name: "PA13", pin_type: Io},
+    Pin {position: "35", name: "PF6", pin_type: Io},
+    Pin {position: "36", name: "PF7", pin_type: Io},
+    Pin {position: "37", name: "PA14", pin_type: Io},
+    Pin {position: "38", name: "PA15", pin_type: Io},
+    Pin {position: "39", name: "PB3", pin_type: Io},
+    Pin {position: "40", name: "PB4", pin_type: Io},
+    Pin {position: "41", name: "PB5", pin_type: Io},
+    Pin {position: "42", name: "PB6", pin_type: Io},
+    Pin {position: "43", name: "PB7", pin_type: Io},
+    Pin {position: "44", name: "BOOT0", pin_type: Boot},
+    Pin {position: "45", name: "PB8", pin_type: Io},
+    Pin {position: "46", name: "PB9", pin_type: Io},
+    Pin {position: "47", name: "VSS", pin_type: Power},
+    Pin {position: "48", name: "VDD", pin_type: Power},
+];
+
+static STM32F031G_4_6_UX: &[Pin] = &[
+    Pin {position: "1", name: "BOOT0", pin_type: Boot},
+    Pin {position: "2", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "3", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "4", name: "NRST", pin_type: Reset},
+    Pin {position: "5", name: "VDDA", pin_type: Power},
+    Pin {position: "6", name: "PA0", pin_type: Io},
+    Pin {position: "7", name: "PA1", pin_type: Io},
+    Pin {position: "8", name: "PA2", pin_type: Io},
+    Pin {position: "9", name: "PA3", pin_type: Io},
+    Pin {position: "10", name: "PA4", pin_type: Io},
+    Pin {position: "11", name: "PA5", pin_type: Io},
+    Pin {position: "12", name: "PA6", pin_type: Io},
+    Pin {position: "13", name: "PA7", pin_type: Io},
+    Pin {position: "14", name: "PB0", pin_type: Io},
+    Pin {position: "15", name: "PB1", pin_type: Io},
+    Pin {position: "16", name: "VSS", pin_type: Power},
+    Pin {position: "17", name: "VDD", pin_type: Power},
+    Pin {position: "18", name: "PA9", pin_type: Io},
+    Pin {position: "19", name: "PA10", pin_type: Io},
+    Pin {position: "20", name: "PA13", pin_type: Io},
+    Pin {position: "21", name: "PA14", pin_type: Io},
+    Pin {position: "22", name: "PA15", pin_type: Io},
+    Pin {position: "23", name: "PB3", pin_type: Io},
+    Pin {position: "24", name: "PB4", pin_type: Io},
+    Pin {position: "25", name: "PB5", pin_type: Io},
+    Pin {position: "26", name: "PB6", pin_type: Io},
+    Pin {position: "27", name: "PB7", pin_type: Io},
+    Pin {position: "28", name: "VSS", pin_type: Power},
+];
+
+static STM32F042C_4_6_TX: &[Pin] = &[
+    Pin {position: "1", name: "VBAT", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "VSSA", pin_type: Power},
+    Pin {position: "9", name: "VDDA", pin_type: Power},
+    Pin {position: "10", name: "PA0", pin_type: Io},
+    Pin {position: "11", name: "PA1", pin_type: Io},
+    Pin {position: "12", name: "PA2", pin_type: Io},
+    Pin {position: "13", name: "PA3", pin_type: Io},
+    Pin {position: "14", name: "PA4", pin_type: Io},
+    Pin {position: "15", name: "PA5", pin_type: Io},
+    Pin {position: "16", name: "PA6", pin_type: Io},
+    Pin {position: "17", name: "PA7", pin_type: Io},
+    Pin {position: "18", name: "PB0", pin_type: Io},
+    Pin {position: "19", name: "PB1", pin_type: Io},
+    Pin {position: "20", name: "PB2", pin_type: Io},
+    Pin {position: "21", name: "PB10", pin_type: Io},
+    Pin {position: "22", name: "PB11", pin_type: Io},
+    Pin {position: "23", name: "VSS", pin_type: Power},
+    Pin {position: "24", name: "VDD", pin_type: Power},
+    Pin {position: "25", name: "PB12", pin_type: Io},
+    Pin {position: "26", name: "PB13", pin_type: Io},
+    Pin {position: "27", name: "PB14", pin_type: Io},
+    Pin {position: "28", name: "PB15", pin_type: Io},
+    Pin {position: "29", name: "PA8", pin_type: Io},
+    Pin {position: "30", name: "PA9", pin_type: Io},
+    Pin {position: "31", name: "PA10", pin_type: Io},
+    Pin {position: "32", name: "PA11", pin_type: Io},
+    Pin {position: "33", name: "PA12", pin_type: Io},
+    Pin {position: "34", name: "PA13", pin_type: Io},
+    Pin {position: "35", name: "VSS", pin_type: Power},
+    Pin {position: "36", name: "VDDIO2", pin_type: Power},
+    Pin {position: "37", name: "PA14", pin_type: Io},
+    Pin {position: "38", name: "PA15", pin_type: Io},
+    Pin {position: "39", name: "PB3", pin_type: Io},
+    Pin {position: "40", name: "PB4", pin_type: Io},
+    Pin {position: "41", name: "PB5", pin_type: Io},
+    Pin {position: "42", name: "PB6", pin_type: Io},
+    Pin {position: "43", name: "PB7", pin_type: Io},
+    Pin {position: "44", name: "BOOT0", pin_type: Boot},
+    Pin {position: "45", name: "PB8", pin_type: Io},
+    Pin {position: "46", name: "PB9", pin_type: Io},
+    Pin {position: "47", name: "VSS", pin_type: Power},
+    Pin {position: "48", name: "VDD", pin_type: Power},
+];
+
+static STM32F042F_4_6_PX: &[Pin] = &[
+    Pin {position: "1", name: "PB8-BOOT0", pin_type: Io},
+    Pin {position: "2", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "3", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "4", name: "NRST", pin_type: Reset},
+    Pin {position: "5", name: "VDDA", pin_type: Power},
+    Pin {position: "6", name: "PA0", pin_type: Io},
+    Pin {position: "7", name: "PA1", pin_type: Io},
+    Pin {position: "8", name: "PA2", pin_type: Io},
+    Pin {position: "9", name: "PA3", pin_type: Io},
+    Pin {position: "10", name: "PA4", pin_type: Io},
+    Pin {position: "11", name: "PA5", pin_type: Io},
+    Pin {position: "12", name: "PA6", pin_type: Io},
+    Pin {position: "13", name: "PA7", pin_type: Io},
+    Pin {position: "14", name: "PB1", pin_type: Io},
+    Pin {position: "15", name: "VSS", pin_type: Power},
+    Pin {position: "16", name: "VDD", pin_type: Power},
+    Pin {position: "17", name: "PA9", pin_type: Io},
+    Pin {position: "18", name: "PA10", pin_type: Io},
+    Pin {position: "19", name: "PA13", pin_type: Io},
+    Pin {position: "20", name: "PA14", pin_type: Io},
+];
+
+static STM32F042G_4_6_UX: &[Pin] = &[
+    Pin {position: "1", name: "VDD", pin_type: Power},
+    Pin {position: "2", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "3", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "4", name: "NRST", pin_type: Reset},
+    Pin {position: "5", name: "VDDA", pin_type: Power},
+    Pin {position: "6", name: "PA0", pin_type: Io},
+    Pin {position: "7", name: "PA1", pin_type: Io},
+    Pin {position: "8", name: "PA2", pin_type: Io},
+    Pin {position: "9", name: "PA3", pin_type: Io},
+    Pin {position: "10", name: "PA4", pin_type: Io},
+    Pin {position: "11", name: "PA5", pin_type: Io},
+    Pin {position: "12", name: "PA6", pin_type: Io},
+    Pin {position: "13", name: "PA7", pin_type: Io},
+    Pin {position: "14", name: "PB0", pin_type: Io},
+    Pin {position: "15", name: "PB1", pin_type: Io},
+    Pin {position: "16", name: "VSS", pin_type: Power},
+    Pin {position: "17", name: "VDDIO2", pin_type: Power},
+    Pin {position: "18", name: "PA9", pin_type: Io},
+    Pin {position: "19", name: "PA10", pin_type: Io},
+    Pin {position: "20", name: "PA11", pin_type: Io},
+    Pin {position: "21", name: "PA12", pin_type: Io},
+    Pin {position: "22", name: "PA13", pin_type: Io},
+    Pin {position: "23", name: "PA14", pin_type: Io},
+    Pin {position: "24", name: "PA15", pin_type: Io},
+    Pin {position: "25", name: "PB3", pin_type: Io},
+    Pin {position: "26", name: "PB4", pin_type: Io},
+    Pin {position: "27", name: "PB5", pin_type: Io},
+    Pin {position: "28", name: "PB8-BOOT0", pin_type: Io},
+];
+
+static STM32F042K_4_6_TX: &[Pin] = &[
+    Pin {position: "1", name: "VDD", pin_type: Power},
+    Pin {position: "2", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "3", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "4", name: "NRST", pin_type: Reset},
+    Pin {position: "5", name: "VDDA", pin_type: Power},
+    Pin {position: "6", name: "PA0", pin_type: Io},
+    Pin {position: "7", name: "PA1", pin_type: Io},
+    Pin {position: "8", name: "PA2", pin_type: Io},
+    Pin {position: "9", name: "PA3", pin_type: Io},
+    Pin {position: "10", name: "PA4", pin_type: Io},
+    Pin {position: "11", name: "PA5", pin_type: Io},
+    Pin {position: "12", name: "PA6", pin_type: Io},
+    Pin {position: "13", name: "PA7", pin_type: Io},
+    Pin {position: "14", name: "PB0", pin_type: Io},
+    Pin {position: "15", name: "PB1", pin_type: Io},
+    Pin {position: "16", name: "VSS", pin_type: Power},
+    Pin {position: "17", name: "VDDIO2", pin_type: Power},
+    Pin {position: "18", name: "PA8", pin_type: Io},
+    Pin {position: "19", name: "PA9", pin_type: Io},
+    Pin {position: "20", name: "PA10", pin_type: Io},
+    Pin {position: "21", name: "PA11", pin_type: Io},
+    Pin {position: "22", name: "PA12", pin_type: Io},
+    Pin {position: "23", name: "PA13", pin_type: Io},
+    Pin {position: "24", name: "PA14", pin_type: Io},
+    Pin {position: "25", name: "PA15", pin_type: Io},
+    Pin {position: "26", name: "PB3", pin_type: Io},
+    Pin {position: "27", name: "PB4", pin_type: Io},
+    Pin {position: "28", name: "PB5", pin_type: Io},
+    Pin {position: "29", name: "PB6", pin_type: Io},
+    Pin {position: "30", name: "PB7", pin_type: Io},
+    Pin {position: "31", name: "PB8-BOOT0", pin_type: Io},
+    Pin {position: "32", name: "VSS", pin_type: Power},
+];
+
+static STM32F051R_4_6_8_TX: &[Pin] = &[
+    Pin {position: "1", name: "VBAT", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "PC0", pin_type: Io},
+    Pin {position: "9", name: "PC1", pin_type: Io},
+    Pin {position: "10", name: "PC2", pin_type: Io},
+    Pin {position: "11", name: "PC3", pin_type: Io},
+    Pin {position: "12", name: "VSSA", pin_type: Power},
+    Pin {position: "13", name: "VDDA", pin_type: Power},
+    Pin {position: "14", name: "PA0", pin_type: Io},
+    Pin {position: "15", name: "PA1", pin_type: Io},
+    Pin {position: "16", name: "PA2", pin_type: Io},
+    Pin {position: "17", name: "PA3", pin_type: Io},
+    Pin {position: "18", name: "PF4", pin_type: Io},
+    Pin {position: "19", name: "PF5", pin_type: Io},
+    Pin {position: "20", name: "PA4", pin_type: Io},
+    Pin {position: "21", name: "PA5", pin_type: Io},
+    Pin {position: "22", name: "PA6", pin_type: Io},
+    Pin {position: "23", name: "PA7", pin_type: Io},
+    Pin {position: "24", name: "PC4", pin_type: Io},
+    Pin {position: "25", name: "PC5", pin_type: Io},
+    Pin {position: "26", name: "PB0", pin_type: Io},
+    Pin {position: "27", name: "PB1", pin_type: Io},
+    Pin {position: "28", name: "PB2", pin_type: Io},
+    Pin {position: "29", name: "PB10", pin_type: Io},
+    Pin {position: "30", name: "PB11", pin_type: Io},
+    Pin {position: "31", name: "VSS", pin_type: Power},
+    Pin {position: "32", name: "VDD", pin_type: Power},
+    Pin {position: "33", name: "PB12", pin_type: Io},
+    Pin {position: "34", name: "PB13", pin_type: Io},
+    Pin {position: "35", name: "PB14", pin_type: Io},
+    Pin {position: "36", name: "PB15", pin_type: Io},
+    Pin {position: "37", name: "PC6", pin_type: Io},
+    Pin {position: "38", name: "PC7", pin_type: Io},
+    Pin {position: "39", name: "PC8", pin_type: Io},
+    Pin {position: "40", name: "PC9", pin_type: Io},
+    Pin {position: "41", name: "PA8", pin_type: Io},
+    Pin {position: "42", name: "PA9", pin_type: Io},
+    Pin {position: "43", name: "PA10", pin_type: Io},
+    Pin {position: "44", name: "PA11", pin_type: Io},
+    Pin {position: "45", name: "PA12", pin_type: Io},
+    Pin {position: "46", name: "PA13", pin_type: Io},
+    Pin {position: "47", name: "PF6", pin_type: Io},
+    Pin {position: "48", name: "PF7", pin_type: Io},
+    Pin {position: "49", name: "PA14", pin_type: Io},
+    Pin {position: "50", name: "PA15", pin_type: Io},
+    Pin {position: "51", name: "PC10", pin_type: Io},
+    Pin {position: "52", name: "PC11", pin_type: Io},
+    Pin {position: "53", name: "PC12", pin_type: Io},
+    Pin {position: "54", name: "PD2", pin_type: Io},
+    Pin {position: "55", name: "PB3", pin_type: Io},
+    Pin {position: "56", name: "PB4", pin_type: Io},
+    Pin {position: "57", name: "PB5", pin_type: Io},
+    Pin {position: "58", name: "PB6", pin_type: Io},
+    Pin {position: "59", name: "PB7", pin_type: Io},
+    Pin {position: "60", name: "BOOT0", pin_type: Boot},
+    Pin {position: "61", name: "PB8", pin_type: Io},
+    Pin {position: "62", name: "PB9", pin_type: Io},
+    Pin {position: "63", name: "VSS", pin_type: Power},
+    Pin {position: "64", name: "VDD", pin_type: Power},
+];
+
+static STM32F070CBTX: &[Pin] = &[
+    Pin {position: "1", name: "VDD", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "VSSA", pin_type: Power},
+    Pin {position: "9", name: "VDDA", pin_type: Power},
+    Pin {position: "10", name: "PA0", pin_type: Io},
+    Pin {position: "11", name: "PA1", pin_type: Io},
+    Pin {position: "12", name: "PA2", pin_type: Io},
+    Pin {position: "13", name: "PA3", pin_type: Io},
+    Pin {position: "14", name: "PA4", pin_type: Io},
+    Pin {position: "15", name: "PA5", pin_type: Io},
+    Pin {position: "16", name: "PA6", pin_type: Io},
+    Pin {position: "17", name: "PA7", pin_type: Io},
+    Pin {position: "18", name: "PB0", pin_type: Io},
+    Pin {position: "19", name: "PB1", pin_type: Io},
+    Pin {position: "20", name: "PB2", pin_type: Io},
+    Pin {position: "21", name: "PB10", pin_type: Io},
+    Pin {position: "22", name: "PB11", pin_type: Io},
+    Pin {position: "23", name: "VSS", pin_type: Power},
+    Pin {position: "24", name: "VDD", pin_type: Power},
+    Pin {position: "25", name: "PB12", pin_type: Io},
+    Pin {position: "26", name: "PB13", pin_type: Io},
+    Pin {position: "27", name: "PB14", pin_type: Io},
+    Pin {position: "28", name: "PB15", pin_type: Io},
+    Pin {position: "29", name: "PA8", pin_type: Io},
+    Pin {position: "30", name: "PA9", pin_type: Io},
+    Pin {position: "31", name: "PA10", pin_type: Io},
+    Pin {position: "32", name: "PA11", pin_type: Io},
+    Pin {position: "33", name: "PA12", pin_type: Io},
+    Pin {position: "34", name: "PA13", pin_type: Io},
+    Pin {position: "35", name: "VSS", pin_type: Power},
+    Pin {position: "36", name: "VDDIO2", pin_type: Power},
+    Pin {position: "37", name: "PA14", pin_type: Io},
+    Pin {position: "38", name: "PA15", pin_type: Io},
+    Pin {position: "39", name: "PB3", pin_type: Io},
+    Pin {position: "40", name: "PB4", pin_type: Io},
+    Pin {position: "41", name: "PB5", pin_type: Io},
+    Pin {position: "42", name: "PB6", pin_type: Io},
+    Pin {position: "43", name: "PB7", pin_type: Io},
+    Pin {position: "44", name: "BOOT0", pin_type: Boot},
+    Pin {position: "45", name: "PB8", pin_type: Io},
+    Pin {position: "46", name: "PB9", pin_type: Io},
+    Pin {position: "47", name: "VSS", pin_type: Power},
+    Pin {position: "48", name: "VDD", pin_type: Power},
+];
+
+static STM32F070RBTX: &[Pin] = &[
+    Pin {position: "1", name: "VDD", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "PC0", pin_type: Io},
+    Pin {position: "9", name: "PC1", pin_type: Io},
+    Pin {position: "10", name: "PC2", pin_type: Io},
+    Pin {position: "11", name: "PC3", pin_type: Io},
+    Pin {position: "12", name: "VSSA", pin_type: Power},
+    Pin {position: "13", name: "VDDA", pin_type: Power},
+    Pin {position: "14", name: "PA0", pin_type: Io},
+    Pin {position: "15", name: "PA1", pin_type: Io},
+    Pin {position: "16", name: "PA2", pin_type: Io},
+    Pin {position: "17", name: "PA3", pin_type: Io},
+    Pin {position: "18", name: "VSS", pin_type: Power},
+    Pin {position: "19", name: "VDD", pin_type: Power},
+    Pin {position: "20", name: "PA4", pin_type: Io},
+    Pin {position: "21", name: "PA5", pin_type: Io},
+    Pin {position: "22", name: "PA6", pin_type: Io},
+    Pin {position: "23", name: "PA7", pin_type: Io},
+    Pin {position: "24", name: "PC4", pin_type: Io},
+    Pin {position: "25", name: "PC5", pin_type: Io},
+    Pin {position: "26", name: "PB0", pin_type: Io},
+    Pin {position: "27", name: "PB1", pin_type: Io},
+    Pin {position: "28", name: "PB2", pin_type: Io},
+    Pin {position: "29", name: "PB10", pin_type: Io},
+    Pin {position: "30", name: "PB11", pin_type: Io},
+    Pin {position: "31", name: "VSS", pin_type: Power},
+    Pin {position: "32", name: "VDD", pin_type: Power},
+    Pin {position: "33", name: "PB12", pin_type: Io},
+    Pin {position: "34", name: "PB13", pin_type: Io},
+    Pin {position: "35", name: "PB14", pin_type: Io},
+    Pin {position: "36", name: "PB15", pin_type: Io},
+    Pin {position: "37", name: "PC6", pin_type: Io},
+    Pin {position: "38", name: "PC7", pin_type: Io},
+    Pin {position: "39", name: "PC8", pin_type: Io},
+    Pin {position: "40", name: "PC9", pin_type: Io},
+    Pin {position: "41", name: "PA8", pin_type: Io},
+    Pin {position: "42", name: "PA9", pin_type: Io},
+    Pin {position: "43", name: "PA10", pin_type: Io},
+    Pin {position: "44", name: "PA11", pin_type: Io},
+    Pin {position: "45", name: "PA12", pin_type: Io},
+    Pin {position: "46", name: "PA13", pin_type: Io},
+    Pin {position: "47", name: "VSS", pin_type: Power},
+    Pin {position: "48", name: "VDDIO2", pin_type: Power},
+    Pin {position: "49", name: "PA14", pin_type: Io},
+    Pin {position: "50", name: "PA15", pin_type: Io},
+    Pin {position: "51", name: "PC10", pin_type: Io},
+    Pin {position: "52", name: "PC11", pin_type: Io},
+    Pin {position: "53", name: "PC12", pin_type: Io},
+    Pin {position: "54", name: "PD2", pin_type: Io},
+    Pin {position: "55", name: "PB3", pin_type: Io},
+    Pin {position: "56", name: "PB4", pin_type: Io},
+    Pin {position: "57", name: "PB5", pin_type: Io},
+    Pin {position: "58", name: "PB6", pin_type: Io},
+    Pin {position: "59", name: "PB7", pin_type: Io},
+    Pin {position: "60", name: "BOOT0", pin_type: Boot},
+    Pin {position: "61", name: "PB8", pin_type: Io},
+    Pin {position: "62", name: "PB9", pin_type: Io},
+    Pin {position: "63", name: "VSS", pin_type: Power},
+    Pin {position: "64", name: "VDD", pin_type: Power},
+];
+
+static STM32F071RBTX: &[Pin] = &[
+    Pin {position: "1", name: "VBAT", pin_type: Power},
+    Pin {position: "2", name: "PC13", pin_type: Io},
+    Pin {position: "3", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "4", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "5", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "6", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "7", name: "NRST", pin_type: Reset},
+    Pin {position: "8", name: "PC0", pin_type: Io},
+    Pin {position: "9", name: "PC1", pin_type: Io},
+    Pin {position: "10", name: "PC2", pin_type: Io},
+    Pin {position: "11", name: "PC3", pin_type: Io},
+    Pin {position: "12", name: "VSSA", pin_type: Power},
+    Pin {position: "13", name: "VDDA", pin_type: Power},
+    Pin {position: "14", name: "PA0", pin_type: Io},
+    Pin {position: "15", name: "PA1", pin_type: Io},
+    Pin {position: "16", name: "PA2", pin_type: Io},
+    Pin {position: "17", name: "PA3", pin_type: Io},
+    Pin {position: "18", name: "VSS", pin_type: Power},
+    Pin {position: "19", name: "VDD", pin_type: Power},
+    Pin {position: "20", name: "PA4", pin_type: Io},
+    Pin {position: "21", name: "PA5", pin_type: Io},
+    Pin {position: "22", name: "PA6", pin_type: Io},
+    Pin {position: "23", name: "PA7", pin_type: Io},
+    Pin {position: "24", name: "PC4", pin_type: Io},
+    Pin {position: "25", name: "PC5", pin_type: Io},
+    Pin {position: "26", name: "PB0", pin_type: Io},
+    Pin {position: "27", name: "PB1", pin_type: Io},
+    Pin {position: "28", name: "PB2", pin_type: Io},
+    Pin {position: "29", name: "PB10", pin_type: Io},
+    Pin {position: "30", name: "PB11", pin_type: Io},
+    Pin {position: "31", name: "VSS", pin_type: Power},
+    Pin {position: "32", name: "VDD", pin_type: Power},
+    Pin {position: "33", name: "PB12", pin_type: Io},
+    Pin {position: "34", name: "PB13", pin_type: Io},
+    Pin {position: "35", name: "PB14", pin_type: Io},
+    Pin {position: "36", name: "PB15", pin_type: Io},
+    Pin {position: "37", name: "PC6", pin_type: Io},
+    Pin {position: "38", name: "PC7", pin_type: Io},
+    Pin {position: "39", name: "PC8", pin_type: Io},
+    Pin {position: "40", name: "PC9", pin_type: Io},
+    Pin {position: "41", name: "PA8", pin_type: Io},
+    Pin {position: "42", name: "PA9", pin_type: Io},
+    Pin {position: "43", name: "PA10", pin_type: Io},
+    Pin {position: "44", name: "PA11", pin_type: Io},
+    Pin {position: "45", name: "PA12", pin_type: Io},
+    Pin {position: "46", name: "PA13", pin_type: Io},
+    Pin {position: "47", name: "VSS", pin_type: Power},
+    Pin {position: "48", name: "VDDIO2", pin_type: Power},
+    Pin {position: "49", name: "PA14", pin_type: Io},
+    Pin {position: "50", name: "PA15", pin_type: Io},
+    Pin {position: "51", name: "PC10", pin_type: Io},
+    Pin {position: "52", name: "PC11", pin_type: Io},
+    Pin {position: "53", name: "PC12", pin_type: Io},
+    Pin {position: "54", name: "PD2", pin_type: Io},
+    Pin {position: "55", name: "PB3", pin_type: Io},
+    Pin {position: "56", name: "PB4", pin_type: Io},
+    Pin {position: "57", name: "PB5", pin_type: Io},
+    Pin {position: "58", name: "PB6", pin_type: Io},
+    Pin {position: "59", name: "PB7", pin_type: Io},
+    Pin {position: "60", name: "BOOT0", pin_type: Boot},
+    Pin {position: "61", name: "PB8", pin_type: Io},
+    Pin {position: "62", name: "PB9", pin_type: Io},
+    Pin {position: "63", name: "VSS", pin_type: Power},
+    Pin {position: "64", name: "VDD", pin_type: Power},
+];
+
+static STM32F071V_8_B_TX: &[Pin] = &[
+    Pin {position: "1", name: "PE2", pin_type: Io},
+    Pin {position: "2", name: "PE3", pin_type: Io},
+    Pin {position: "3", name: "PE4", pin_type: Io},
+    Pin {position: "4", name: "PE5", pin_type: Io},
+    Pin {position: "5", name: "PE6", pin_type: Io},
+    Pin {position: "6", name: "VBAT", pin_type: Power},
+    Pin {position: "7", name: "PC13", pin_type: Io},
+    Pin {position: "8", name: "PC14OSC32_IN", pin_type: Io},
+    Pin {position: "9", name: "PC15OSC32_OUT", pin_type: Io},
+    Pin {position: "10", name: "PF9", pin_type: Io},
+    Pin {position: "11", name: "PF10", pin_type: Io},
+    Pin {position: "12", name: "PF0-OSC_IN", pin_type: Io},
+    Pin {position: "13", name: "PF1-OSC_OUT", pin_type: Io},
+    Pin {position: "14", name: "NRST", pin_type: Reset},
+    Pin {position: "15", name: "PC0", pin_type: Io},
+    Pin {position: "16", name: "PC1", pin_type: Io},
+    Pin {position: "17", name: "PC2", pin_type: Io},
+    Pin {position: "18", name: "PC3", pin_type: Io},
+    Pin {position: "19", name: "PF2", pin_type: Io},
+    Pin {position: "20", name: "PF3", pin_type: Io},
+    Pin {position: "21", name: "VSSA", pin_type: Power},
+    Pin {position: "22", name: "VDDA", pin_type: Power},
+    Pin {position: "23", name: "PA0", pin_type: Io},
+    Pin {position: "24", name: "PA1", pin_type: Io},
+    Pin {position: "25", name: "PA2", pin_type: Io},
+    Pin {position: "26", name: "PA3", pin_type: Io},
+    Pin {position: "27", name: "PF4", pin_type: Io},
+    Pin {position: "28", name: "PF5", pin_type: Io},
+    Pin {position: "29", name: "PA4", pin_type: Io},
+    Pin {position: "30", name: "PA5", pin_type: Io},
+    Pin {position: "31", name: "PA6", pin_type: Io},
+    Pin {position: "32", name: "PA7", pin_type: Io},
+    Pin {position: "33", name: "PC4", pin_type: Io},
+    Pin {position: "34", name: "PC5", pin_type: Io},
+    Pin {position: "35", name: "PB0", pin_type: Io},
+    Pin {position: "36", name: "PB1", pin_type: Io},
+    Pin {position: "37", name: "PB2", pin_type: Io},
+    Pin {position: "38", name: "PE7", pin_type: Io},
+    Pin {position: "39", name: "PE8", pin_type: Io},
+    Pin {position: "40", name: "PE9", pin_type: Io},
+    Pin {position: "41", name: "PE10", pin_type: Io},
+    Pin {position: "42", name: "PE11", pin_type: Io},
+    Pin {position: "43", name: "PE12", pin_type: Io},
+    Pin {position: "44", name: "PE13", pin_type: Io},
+    Pin {position: "45", name: "PE14", pin_type: Io},
+    Pin {position: "46", name: "PE15", pin_type: Io},
+    Pin {position: "47", name: "PB10", pin_type: Io},
+    Pin {position: "48", name: "PB11", pin_type: Io},
+    Pin {position: "49", name: "VSS", pin_type: Power},
+    Pin {position: "50", name: "VDD", pin_type: Power},
+    Pin {position: "51", name: "PB12", pin_type: Io},
+    Pin {position: "52", name: "PB13", pin_type: Io},
+    Pin {position: "53", name: "PB14", pin_type: Io},
+    Pin {position: "54", name: "PB15", pin_type: Io},
+    Pin {position: "55", name: "PD8", pin_type: Io},
+    Pin {position: "56", name: "PD9", pin_type: Io},
+    Pin {position: "57", name: "PD10", pin_type: Io},
+    Pin {position: "58", name: "PD11", pin_type: Io},
+    Pin {position: "59", name: "PD12", pin_type: Io},
+    Pin {position: "60", name: "PD13", pin_type: Io},
+    Pin {position: "61", name: "PD14", pin_type: Io},
+    Pin {position: "62", name: "PD15", pin_type: Io},
+    Pin {position: "63", name: "PC6", pin_type: Io},
+    Pin {position: "64", name: "PC7", pin_type: Io},
+    Pin {position: "65", name: "PC8", pin_type: Io},
+    Pin {position: "66", name: "PC9", pin_type: Io},
+    Pin {position: "67", name: "PA8", pin_type: Io},
+    Pin {position: "68", name: "PA9", pin_type: Io},
+    Pin {position: "69", name: "PA10", pin_type: Io},
+    Pin {position: "70", name: "PA11", pin_type: Io},
+    Pin {position: "71", name: "PA12", pin_type: Io},
+    Pin {position: "72", name: "PA13", pin_type: Io},
+    Pin {position: "73", name: "PF6", pin_type: Io},
+    Pin {position: "74", name: "VSS", pin_type: Power},
+    Pin {position: "75", name: "VDDIO2", pin_type: Power},
+    Pin {position: "76", name: "PA14", pin_type: Io},
+    Pin {position: "77", name: "PA15", pin_type: Io},
+    Pin {position: "78", name: "PC10", pin_type: Io},
+    Pin {position: "79", name: "PC11", pin_type: Io},
+    Pin {position: "80", name: "PC12", pin_type: Io},
+    Pin {position: "81", name: "PD0", pin_type: Io},
+    Pin {position: "82", name: "PD1", pin_type: Io},
+    Pin {position: "83", name: "PD2", pin_type: Io},
+    Pin {position: "84", name: "PD3", pin_type: Io},
+    Pin {position: "85", name: "PD4", pin_type: Io},
+    Pin {position: "86", name: "PD5", pin_type: Io},
+    Pin {position: "87", name: "PD6", pin_type: Io},
+    Pin {position: "88", name: "PD7", pin_type: Io},
+    Pin {position: "89", name: "PB3", pin_type: Io},
+    Pin {position: "90", name: "PB4", pin_type: Io},
+    Pin {position: "91", name: "PB5", pin_type: Io},
+    Pin {position: "92", name: "PB6", pin_type: Io},
+    Pin {position: "93", name: "PB7", pin_type: Io},
+    Pin {position: "94", name: "BOOT0", pin_type: Boot},
+    Pin {position: "95", name: "PB8", pin_type: Io},
+    Pin {position: "96", name: "PB9", pin_type: Io},
+    Pin {position: "97", name: "PE0", pin_type: Io},
+    Pin {position: "98", name: "PE1", pin_type: Io},
+    Pin {position: "99", name: "VSS", pin_type: Power},
+    Pin {position: "100", name: "VDD", pin_type: Power},
+];
@@ -0,0 +1,598 @@
+use super::Vector;
+use phf::{phf_map, Map};
+
+// interrupt vectors of every MCU, CubeMX IRQn name and stm32f0 PAC Interrupt variant
+pub static VECTORS: Map<&str, &[Vector]> = phf_map! {
+    "STM32F030C6Tx" => F030X6,
+    "STM32F030C8Tx" => F030X8,
+    "STM32F030CCTx" => F030XC,
+    "STM32F030F4Px" => F030X6,
+    "STM32F030K6Tx" => F030X6,
+    "STM32F030R8Tx" => F030X8,
+    "STM32F030RCTx" => F030XC,
+    "STM32F031C4Tx" => F031,
+    "STM32F031C6Tx" => F031,
+    "STM32F031E6Yx" => F031,
+    "STM32F031F4Px" => F031,
+    "STM32F031F6Px" => F031,
+    "STM32F031G4Ux" => F031,
+    "STM32F031G6Ux" => F031,
+    "STM32F031K4Ux" => F031,
+    "STM32F031K6Tx" => F031,
+    "STM32F031K6Ux" => F031,
+    "STM32F038C6Tx" => F038,
+    "STM32F038E6Yx" => F038,
+    "STM32F038F6Px" => F038,
+    "STM32F038G6Ux" => F038,
+    "STM32F038K6Ux" => F038,
+    "STM32F042C4Tx" => F042,
+    "STM32F042C4Ux" => F042,
+    "STM32F042C6Tx" => F042,
+    "STM32F042C6Ux" => F042,
+    "STM32F042F4Px" => F042,
+    "STM32F042F6Px" => F042,
+    "STM32F042G4Ux" => F042,
+    "STM32F042G6Ux" => F042,
+    "STM32F042K4Tx" => F042,
+    "STM32F042K4Ux" => F042,
+    "STM32F042K6Tx" => F042,
+    "STM32F042K6Ux" => F042,
+    "STM32F042T6Yx" => F042,
+    "STM32F048C6Ux" => F048,
+    "STM32F048G6Ux" => F048,
+    "STM32F048T6Yx" => F048,
+    "STM32F051C4Tx" => F051,
+    "STM32F051C4Ux" => F051,
+    "STM32F051C6Tx" => F051,
+    "STM32F051C6Ux" => F051,
+    "STM32F051C8Tx" => F051,
+    "STM32F051C8Ux" => F051,
+    "STM32F051K4Tx" => F051,
+    "STM32F051K4Ux" => F051,
+    "STM32F051K6Tx" => F051,
+    "STM32F051K6Ux" => F051,
+    "STM32F051K8Tx" => F051,
+    "STM32F051K8Ux" => F051,
+    "STM32F051R4Tx" => F051,
+    "STM32F051R6Tx" => F051,
+    "STM32F051R8Hx" => F051,
+    "STM32F051R8Tx" => F051,
+    "STM32F051T8Yx" => F051,
+    "STM32F058C8Ux" => F058,
+    "STM32F058R8Hx" => F058,
+    "STM32F058R8Tx" => F058,
+    "STM32F058T8Yx" => F058,
+    "STM32F070C6Tx" => F070X6,
+    "STM32F070CBTx" => F070XB,
+    "STM32F070F6Px" => F070X6,
+    "STM32F070RBTx" => F070XB,
+    "STM32F071C8Tx" => F071,
+    "STM32F071C8Ux" => F071,
+    "STM32F071CBTx" => F071,
+    "STM32F071CBUx" => F071,
+    "STM32F071CBYx" => F071,
+    "STM32F071RBTx" => F071,
+    "STM32F071V8Hx" => F071,
+    "STM32F071V8Tx" => F071,
+    "STM32F071VBHx" => F071,
+    "STM32F071VBTx" => F071,
+    "STM32F072C8Tx" => F072,
+    "STM32F072C8Ux" => F072,
+    "STM32F072CBTx" => F072,
+    "STM32F072CBUx" => F072,
+    "STM32F072CBYx" => F072,
+    "STM32F072R8Tx" => F072,
+    "STM32F072RBHx" => F072,
+    "STM32F072RBIx" => F072,
+    "STM32F072RBTx" => F072,
+    "STM32F072V8Hx" => F072,
+    "STM32F072V8Tx" => F072,
+    "STM32F072VBHx" => F072,
+    "STM32F072VBTx" => F072,
+    "STM32F078CBTx" => F078,
+    "STM32F078CBUx" => F078,
+    "STM32F078CBYx" => F078,
+    "STM32F078RBHx" => F078,
+    "STM32F078RBTx" => F078,
+    "STM32F078VBHx" => F078,
+    "STM32F078VBTx" => F078,
+    "STM32F091CBTx" => F091,
+    "STM32F091CBUx" => F091,
+    "STM32F091CCTx" => F091,
+    "STM32F091CCUx" => F091,
+    "STM32F091RBTx" => F091,
+    "STM32F091RCHx" => F091,
+    "STM32F091RCTx" => F091,
+    "STM32F091RCYx" => F091,
+    "STM32F091VBTx" => F091,
+    "STM32F091VCHx" => F091,
+    "STM32F091VCTx" => F091,
+    "STM32F098CCTx" => F098,
+    "STM32F098CCUx" => F098,
+    "STM32F098RCHx" => F098,
+    "STM32F098RCTx" => F098,
+    "STM32F098RCYx" => F098,
+    "STM32F098VCHx" => F098,
+    "STM32F098VCTx" => F098,
+};
+
+static F030X6: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+];
+
+static F030X8: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_IRQn", pac_name: "TIM6"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+];
+
+static F030XC: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_IRQn", pac_name: "TIM6"},
+    Vector {number: 18, cube_name: "TIM7_IRQn", pac_name: "TIM7"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 29, cube_name: "USART3_6_IRQn", pac_name: "USART3_4_5_6"},
+];
+
+static F031: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "PVD_IRQn", pac_name: "PVD"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+];
+
+static F038: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+];
+
+static F042: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "PVD_VDDIO2_IRQn", pac_name: "PVD"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_CRS_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+    Vector {number: 31, cube_name: "USB_IRQn", pac_name: "USB"},
+];
+
+static F048: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "VDDIO2_IRQn", pac_name: "VDDIO2"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_CRS_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+    Vector {number: 31, cube_name: "USB_IRQn", pac_name: "USB"},
+];
+
+static F051: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "PVD_IRQn", pac_name: "PVD"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_COMP_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_DAC_IRQn", pac_name: "TIM6_DAC"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+];
+
+static F058: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_COMP_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_DAC_IRQn", pac_name: "TIM6_DAC"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+];
+
+static F070X6: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 31, cube_name: "USB_IRQn", pac_name: "USB"},
+];
+
+static F070XB: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_IRQn", pac_name: "RCC"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_IRQn", pac_name: "DMA1_CH4_5"},
+    Vector {number: 12, cube_name: "ADC1_IRQn", pac_name: "ADC"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_IRQn", pac_name: "TIM6"},
+    Vector {number: 18, cube_name: "TIM7_IRQn", pac_name: "TIM7"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 29, cube_name: "USART3_4_IRQn", pac_name: "USART3_4_5_6"},
+    Vector {number: 31, cube_name: "USB_IRQn", pac_name: "USB"},
+];
+
+static F071: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "PVD_VDDIO2_IRQn", pac_name: "PVD"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_CRS_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_6_7_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_COMP_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_DAC_IRQn", pac_name: "TIM6_DAC"},
+    Vector {number: 18, cube_name: "TIM7_IRQn", pac_name: "TIM7"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 29, cube_name: "USART3_4_IRQn", pac_name: "USART3_4_5_6_7_8"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+];
+
+static F072: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "PVD_VDDIO2_IRQn", pac_name: "PVD"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_CRS_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_6_7_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_COMP_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_DAC_IRQn", pac_name: "TIM6_DAC"},
+    Vector {number: 18, cube_name: "TIM7_IRQn", pac_name: "TIM7"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 29, cube_name: "USART3_4_IRQn", pac_name: "USART3_4"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+    Vector {number: 31, cube_name: "USB_IRQn", pac_name: "USB"},
+];
+
+static F078: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "VDDIO2_IRQn", pac_name: "VDDIO2"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_CRS_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Channel1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Channel2_3_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Channel4_5_6_7_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_COMP_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_DAC_IRQn", pac_name: "TIM6_DAC"},
+    Vector {number: 18, cube_name: "TIM7_IRQn", pac_name: "TIM7"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 29, cube_name: "USART3_4_IRQn", pac_name: "USART3_4_5_6_7_8"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+    Vector {number: 31, cube_name: "USB_IRQn", pac_name: "USB"},
+];
+
+static F091: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "PVD_VDDIO2_IRQn", pac_name: "PVD"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_CRS_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Ch1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Ch2_3_DMA2_Ch1_2_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Ch4_7_DMA2_Ch3_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_COMP_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_DAC_IRQn", pac_name: "TIM6_DAC"},
+    Vector {number: 18, cube_name: "TIM7_IRQn", pac_name: "TIM7"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 29, cube_name: "USART3_8_IRQn", pac_name: "USART3_4_5_6_7_8"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+];
+
+static F098: &[Vector] = &[
+    Vector {number: 0, cube_name: "WWDG_IRQn", pac_name: "WWDG"},
+    Vector {number: 1, cube_name: "VDDIO2_IRQn", pac_name: "VDDIO2"},
+    Vector {number: 2, cube_name: "RTC_IRQn", pac_name: "RTC"},
+    Vector {number: 3, cube_name: "FLASH_IRQn", pac_name: "FLASH"},
+    Vector {number: 4, cube_name: "RCC_CRS_IRQn", pac_name: "RCC_CRS"},
+    Vector {number: 5, cube_name: "EXTI0_1_IRQn", pac_name: "EXTI0_1"},
+    Vector {number: 6, cube_name: "EXTI2_3_IRQn", pac_name: "EXTI2_3"},
+    Vector {number: 7, cube_name: "EXTI4_15_IRQn", pac_name: "EXTI4_15"},
+    Vector {number: 8, cube_name: "TSC_IRQn", pac_name: "TSC"},
+    Vector {number: 9, cube_name: "DMA1_Ch1_IRQn", pac_name: "DMA1_CH1"},
+    Vector {number: 10, cube_name: "DMA1_Ch2_3_DMA2_Ch1_2_IRQn", pac_name: "DMA1_CH2_3"},
+    Vector {number: 11, cube_name: "DMA1_Ch4_7_DMA2_Ch3_5_IRQn", pac_name: "DMA1_CH4_5_6_7"},
+    Vector {number: 12, cube_name: "ADC1_COMP_IRQn", pac_name: "ADC_COMP"},
+    Vector {number: 13, cube_name: "TIM1_BRK_UP_TRG_COM_IRQn", pac_name: "TIM1_BRK_UP_TRG_COM"},
+    Vector {number: 14, cube_name: "TIM1_CC_IRQn", pac_name: "TIM1_CC"},
+    Vector {number: 15, cube_name: "TIM2_IRQn", pac_name: "TIM2"},
+    Vector {number: 16, cube_name: "TIM3_IRQn", pac_name: "TIM3"},
+    Vector {number: 17, cube_name: "TIM6_DAC_IRQn", pac_name: "TIM6_DAC"},
+    Vector {number: 18, cube_name: "TIM7_IRQn", pac_name: "TIM7"},
+    Vector {number: 19, cube_name: "TIM14_IRQn", pac_name: "TIM14"},
+    Vector {number: 20, cube_name: "TIM15_IRQn", pac_name: "TIM15"},
+    Vector {number: 21, cube_name: "TIM16_IRQn", pac_name: "TIM16"},
+    Vector {number: 22, cube_name: "TIM17_IRQn", pac_name: "TIM17"},
+    Vector {number: 23, cube_name: "I2C1_IRQn", pac_name: "I2C1"},
+    Vector {number: 24, cube_name: "I2C2_IRQn", pac_name: "I2C2"},
+    Vector {number: 25, cube_name: "SPI1_IRQn", pac_name: "SPI1"},
+    Vector {number: 26, cube_name: "SPI2_IRQn", pac_name: "SPI2"},
+    Vector {number: 27, cube_name: "USART1_IRQn", pac_name: "USART1"},
+    Vector {number: 28, cube_name: "USART2_IRQn", pac_name: "USART2"},
+    Vector {number: 29, cube_name: "USART3_8_IRQn", pac_name: "USART3_4_5_6_7_8"},
+    Vector {number: 30, cube_name: "CEC_CAN_IRQn", pac_name: "CEC_CAN"},
+];
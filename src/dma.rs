@@ -0,0 +1,178 @@
+use std::collections::hash_map::Entry;
+
+use crate::db::*;
+use crate::*;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct DMA {
+    pub request: String,
+    pub instance: String,
+    pub direction: Option<Direction>,
+    pub mode: Option<Mode>,
+    pub priority: Option<Priority>,
+    pub periph_inc: Option<PeriphInc>,
+    pub mem_inc: Option<MemInc>,
+    pub periph_data_alignment: Option<PeriphDataAlignment>,
+    pub mem_data_alignment: Option<MemDataAlignment>,
+}
+
+pub fn get_dmas(config: &ConfigParams<'_>) -> anyhow::Result<Vec<DMA>> {
+    let mut dmas = Vec::new();
+
+    let dma_params = match config.get("Dma") {
+        Some(dma_params) => dma_params,
+        None => return Ok(dmas),
+    };
+
+    let requests_nb = parse_optional_u32(dma_params, "RequestsNb")?.unwrap_or_default();
+
+    // the requests are listed as Dma.Request0=USART1_TX,
+    // their settings as Dma.USART1_TX.0.Instance=DMA1_Channel2
+    for i in 0..requests_nb {
+        let request = *dma_params
+            .get::<str>(&f!("Request{i}"))
+            .ok_or_else(|| anyhow!("Request{} parameter required", i))?;
+
+        let request_params: HashMap<&str, &str> = dma_params
+            .iter()
+            .filter_map(|(name, &value)| {
+                name.strip_prefix(&f!("{request}.{i}."))
+                    .map(|parameter| (parameter, value))
+            })
+            .collect();
+
+        let instance = request_params
+            .get("Instance")
+            .ok_or_else(|| anyhow!("{} Instance parameter required", request))?
+            .to_string();
+
+        dmas.push(DMA {
+            request: request.to_string(),
+            instance,
+            direction: parse_optional_param(&request_params, "Direction")?,
+            mode: parse_optional_param(&request_params, "Mode")?,
+            priority: parse_optional_param(&request_params, "Priority")?,
+            periph_inc: parse_optional_param(&request_params, "PeriphInc")?,
+            mem_inc: parse_optional_param(&request_params, "MemInc")?,
+            periph_data_alignment: parse_optional_param(&request_params, "PeriphDataAlignment")?,
+            mem_data_alignment: parse_optional_param(&request_params, "MemDataAlignment")?,
+        });
+    }
+
+    Ok(dmas)
+}
+
+/// Checks that every DMA request is on a channel that can serve it
+/// and that the channels and remaps don't conflict
+pub fn validate_dmas(config: &Config) -> anyhow::Result<()> {
+    let mut errors = Vec::new();
+    let mut instances: HashMap<&str, &str> = HashMap::new();
+    let mut remaps: HashMap<&str, (bool, &str)> = HashMap::new();
+
+    for dma in config.dmas.iter() {
+        if let Some(other) = instances.insert(&dma.instance, &dma.request) {
+            errors.push(f!(
+                "{dma.instance} is used by both {other} and {dma.request}"
+            ));
+        }
+
+        // memory to memory transfers can use any channel
+        if dma.request == "MEMTOMEM" {
+            continue;
+        }
+
+        let channels = match get_dma_channels(config, &dma.request) {
+            Ok(channels) => channels,
+            Err(error) => {
+                errors.push(error.to_string());
+                continue;
+            }
+        };
+
+        match channels
+            .iter()
+            .find(|(instance, _)| *instance == dma.instance)
+        {
+            Some((_, DmaRemap::Syscfg(bit, set))) => match remaps.entry(bit) {
+                Entry::Occupied(entry) => {
+                    let (other_set, other) = entry.get();
+                    if other_set != set {
+                        errors.push(f!(
+                            "{dma.request} on {dma.instance} and {other} need different settings of the SYSCFG remap {bit}"
+                        ));
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((*set, &dma.request));
+                }
+            },
+            Some(_) => {}
+            None => {
+                let valid: Vec<&str> = channels.iter().map(|(instance, _)| *instance).collect();
+                errors.push(f!(
+                    "{dma.request} can't use {dma.instance}, valid channels: {}",
+                    valid.join(", ")
+                ));
+            }
+        }
+    }
+
+    ensure!(errors.is_empty(), errors.join("\n"));
+
+    Ok(())
+}
+
+parameter!(
+    Direction,
+    [
+        DMA_PERIPH_TO_MEMORY,
+        DMA_MEMORY_TO_PERIPH,
+        DMA_MEMORY_TO_MEMORY
+    ]
+);
+
+parameter!(Mode, [DMA_NORMAL, DMA_CIRCULAR], default = DMA_NORMAL);
+
+parameter!(
+    Priority,
+    [
+        DMA_PRIORITY_LOW,
+        DMA_PRIORITY_MEDIUM,
+        DMA_PRIORITY_HIGH,
+        DMA_PRIORITY_VERY_HIGH
+    ],
+    default = DMA_PRIORITY_LOW
+);
+
+parameter!(
+    PeriphInc,
+    [DMA_PINC_ENABLE, DMA_PINC_DISABLE],
+    default = DMA_PINC_DISABLE
+);
+
+parameter!(
+    MemInc,
+    [DMA_MINC_ENABLE, DMA_MINC_DISABLE],
+    default = DMA_MINC_ENABLE
+);
+
+parameter!(
+    PeriphDataAlignment,
+    [
+        DMA_PDATAALIGN_BYTE,
+        DMA_PDATAALIGN_HALFWORD,
+        DMA_PDATAALIGN_WORD
+    ],
+    default = DMA_PDATAALIGN_BYTE
+);
+
+parameter!(
+    MemDataAlignment,
+    [
+        DMA_MDATAALIGN_BYTE,
+        DMA_MDATAALIGN_HALFWORD,
+        DMA_MDATAALIGN_WORD
+    ],
+    default = DMA_MDATAALIGN_BYTE
+);
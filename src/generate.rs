@@ -1,3 +1,7 @@
+use anyhow::{anyhow, bail, Context};
+
+use crate::adc::*;
+use crate::dac::*;
 use crate::db::*;
 use crate::gpio::*;
 use crate::i2c::*;
@@ -19,7 +23,6 @@ pub fn generate_main(config: &Config) -> anyhow::Result<String> {
     imports.line("#![no_main]");
     imports.empty_line();
     imports.line("use crate::hal::{prelude::*, stm32};");
-    imports.line("use cortex_m::interrupt;");
     imports.line("use cortex_m_rt::entry;");
     imports.line("use panic_halt as _;");
     imports.line(f!("use {hal} as hal;"));
@@ -33,30 +36,81 @@ pub fn generate_main(config: &Config) -> anyhow::Result<String> {
 
     main_func.line("let mut p = stm32::Peripherals::take().unwrap();");
 
-    add_rcc(&mut main_func, &config);
+    add_rcc(&mut main_func, config);
+
+    add_rcc_extras(&mut main_func, config);
+
+    add_backup_domain(&mut main_func, config);
+
+    add_usb_clock(&mut main_func, config);
+
+    add_mco(&mut main_func, config);
+
+    add_kernel_clocks(&mut main_func, config);
 
-    add_ports(&mut main_func, &config);
+    add_ports(&mut main_func, config);
 
-    add_gpios(&mut main_func, &config);
+    add_gpios(&mut main_func, config)?;
+
+    add_pin_groups(&mut main_func, &mut imports, config)?;
+
+    add_free_pins(&mut main_func, config);
+
+    let statics = add_extis(&mut main_func, &mut imports, config);
+
+    if let Some(adc) = &config.adc {
+        add_adc(&mut main_func, &mut imports, adc, config)?;
+    }
+
+    if let Some(dac) = &config.dac {
+        add_dac(&mut main_func, &mut imports, dac, config)?;
+    }
 
     for spi in config.spis.iter() {
         add_spi(&mut main_func, &mut imports, spi);
     }
 
     for usart in config.usarts.iter() {
-        add_usart(&mut main_func, &mut imports, usart);
+        add_usart(&mut main_func, &mut imports, usart, config)?;
     }
 
     for i2c in config.i2cs.iter() {
-        add_i2c(&mut main_func, &mut imports, i2c);
+        add_i2c(&mut main_func, &mut imports, i2c, config)?;
     }
 
+    let handlers = add_interrupts(&mut main_func, &mut imports, config)?;
+
     main_func.line("loop {}");
 
     main_func.indent_left();
     main_func.line("}");
 
-    Ok(imports.string + "\n" + &main_func.string)
+    let clocks = generate_clocks_mod(config);
+
+    let adc_channels = generate_adc_channels(config)?;
+
+    let pins = generate_pins_mod(config)?;
+
+    let user_constants = generate_user_constants_mod(config);
+
+    let mut main_rs = imports.string
+        + "\n"
+        + &statics.string
+        + &main_func.string
+        + &handlers.string
+        + "\n"
+        + &clocks.string;
+    if !adc_channels.string.is_empty() {
+        main_rs = main_rs + "\n" + &adc_channels.string;
+    }
+    if !pins.string.is_empty() {
+        main_rs = main_rs + "\n" + &pins.string;
+    }
+    if !user_constants.string.is_empty() {
+        main_rs = main_rs + "\n" + &user_constants.string;
+    }
+
+    Ok(main_rs)
 }
 
 fn add_rcc(string: &mut GeneratedString, config: &Config) {
@@ -89,6 +143,242 @@ fn add_rcc(string: &mut GeneratedString, config: &Config) {
     string.empty_line();
 }
 
+/// HSI trimming and the clock security system aren't covered by the HAL
+fn add_rcc_extras(string: &mut GeneratedString, config: &Config) {
+    let mut modifications = Vec::new();
+
+    if let Some(hsi_calibration) = config.rcc.hsi_calibration {
+        if hsi_calibration != HSI_CALIBRATION_RESET {
+            modifications.push(f!("hsitrim().bits({hsi_calibration})"));
+        }
+    }
+    if config.rcc.css_enabled {
+        modifications.push(f!("csson().set_bit()"));
+    }
+
+    if modifications.is_empty() {
+        return;
+    }
+
+    let modifications = modifications.join(".");
+
+    string
+        .line("// HSI trimming and the clock security system aren't configurable through the HAL");
+    string.line("unsafe {");
+    string.indent_right();
+    string.line(f!(
+        "(*stm32::RCC::ptr()).cr.modify(|_, w| w.{modifications});"
+    ));
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+}
+
+/// Starts LSE and LSI and selects the RTC clock, the HAL doesn't cover the backup domain
+fn add_backup_domain(string: &mut GeneratedString, config: &Config) {
+    let rcc = &config.rcc;
+
+    if rcc.lse.is_none() && rcc.rtc_clock_source.is_none() {
+        return;
+    }
+
+    string.line("// low speed oscillators and RTC clock");
+    string.line("unsafe {");
+    string.indent_right();
+    string.line("let rcc_regs = &*stm32::RCC::ptr();");
+
+    // the backup domain is write protected
+    string.line("rcc_regs.apb1enr.modify(|_, w| w.pwren().set_bit());");
+    string.line("(*stm32::PWR::ptr()).cr.modify(|_, w| w.dbp().set_bit());");
+
+    if let Some(lse) = &rcc.lse {
+        let mode = if lse.bypass {
+            f!("lsebyp().set_bit()")
+        } else {
+            f!("lsedrv().bits({:#04b})", lse.drive.bits())
+        };
+        string.line(f!(
+            "rcc_regs.bdcr.modify(|_, w| w.{mode}.lseon().set_bit());"
+        ));
+        string.line("while rcc_regs.bdcr.read().lserdy().bit_is_clear() {}");
+    }
+
+    if rcc.lsi_enabled() {
+        string.line("rcc_regs.csr.modify(|_, w| w.lsion().set_bit());");
+        string.line("while rcc_regs.csr.read().lsirdy().bit_is_clear() {}");
+    }
+
+    if let Some(rtc_clock_source) = rcc.rtc_clock_source {
+        string.line(f!(
+            "rcc_regs.bdcr.modify(|_, w| w.rtcsel().bits({:#04b}).rtcen().set_bit());",
+            rtc_clock_source.bits()
+        ));
+    }
+
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+}
+
+/// Selects the USB clock and sets up the CRS to synchronize HSI48
+fn add_usb_clock(string: &mut GeneratedString, config: &Config) {
+    let rcc = &config.rcc;
+
+    if rcc.usb_clock_source.is_none() && rcc.crs.is_none() {
+        return;
+    }
+
+    string.line("// USB clock and clock recovery system");
+    string.line("unsafe {");
+    string.indent_right();
+    string.line("let rcc_regs = &*stm32::RCC::ptr();");
+
+    if rcc.hsi48_needed() {
+        string.line("rcc_regs.cr2.modify(|_, w| w.hsi48on().set_bit());");
+        string.line("while rcc_regs.cr2.read().hsi48rdy().bit_is_clear() {}");
+    }
+
+    match rcc.usb_clock_source {
+        Some(USBClockSource::RCC_USBCLKSOURCE_HSI48) => {
+            string.line("rcc_regs.cfgr3.modify(|_, w| w.usbsw().clear_bit());")
+        }
+        Some(USBClockSource::RCC_USBCLKSOURCE_PLL) => {
+            string.line("rcc_regs.cfgr3.modify(|_, w| w.usbsw().set_bit());")
+        }
+        None => {}
+    }
+
+    if let Some(crs) = &rcc.crs {
+        let polarity = match crs.polarity {
+            CRSPolarity::RCC_CRS_SYNC_POLARITY_RISING => "clear_bit",
+            CRSPolarity::RCC_CRS_SYNC_POLARITY_FALLING => "set_bit",
+        };
+
+        string.line("rcc_regs.apb1enr.modify(|_, w| w.crsen().set_bit());");
+        string.line("let crs = &*stm32::CRS::ptr();");
+        string.line("crs.cfgr.write(|w| {");
+        string.indent_right();
+        string.line(f!("w.syncsrc().bits({:#04b})", crs.sync_source.bits()));
+        string.indent_right();
+        string.line(f!(".syncpol().{polarity}()"));
+        string.line(f!(".syncdiv().bits({})", crs.prescaler.bits()));
+        string.line(f!(".reload().bits({crs.reload})"));
+        string.line(f!(".felim().bits({crs.error_limit})"));
+        string.indent_left();
+        string.indent_left();
+        string.line("});");
+        string.line(f!(
+            "crs.cr.modify(|_, w| w.trim().bits({crs.hsi48_calibration}).autotrimen().set_bit().cen().set_bit());"
+        ));
+    }
+
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+}
+
+/// Selects the clock output source and prescaler, the pin is set up with the other GPIOs
+fn add_mco(string: &mut GeneratedString, config: &Config) {
+    let mco = match &config.rcc.mco {
+        Some(mco) => mco,
+        None => return,
+    };
+
+    let mut modifications = vec![f!("mco().bits({:#06b})", mco.source.bits())];
+    // MCOPRE only exists on some parts, skip it if it's the reset value
+    if mco.divider != MCODivider::RCC_MCODIV_1 {
+        modifications.push(f!("mcopre().bits({:#05b})", mco.divider.bits()));
+    }
+    match mco.source {
+        MCOSource::RCC_MCO1SOURCE_PLLCLK => modifications.push(f!("pllnodiv().set_bit()")),
+        MCOSource::RCC_MCO1SOURCE_PLLCLK_DIV2 => modifications.push(f!("pllnodiv().clear_bit()")),
+        _ => {}
+    }
+
+    let modifications = modifications.join(".");
+
+    string.line("// microcontroller clock output");
+    string.line("unsafe {");
+    string.indent_right();
+    string.line(f!(
+        "(*stm32::RCC::ptr()).cfgr.modify(|_, w| w.{modifications});"
+    ));
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+}
+
+/// Selects the kernel clocks of the peripherals in RCC_CFGR3
+fn add_kernel_clocks(string: &mut GeneratedString, config: &Config) {
+    if config.rcc.kernel_clocks.is_empty() {
+        return;
+    }
+
+    let modifications: Vec<String> = config
+        .rcc
+        .kernel_clocks
+        .iter()
+        .map(|kernel_clock| {
+            let switch = kernel_clock.switch;
+            let bits = switch.bits(kernel_clock.source);
+            match (switch.is_single_bit(), bits) {
+                (true, 0) => f!("{switch.field}().clear_bit()"),
+                (true, _) => f!("{switch.field}().set_bit()"),
+                (false, _) => f!("{switch.field}().bits({bits:#04b})"),
+            }
+        })
+        .collect();
+    let modifications = modifications.join(".");
+
+    string.line("// peripheral kernel clocks");
+    string.line("unsafe {");
+    string.indent_right();
+    string.line(f!(
+        "(*stm32::RCC::ptr()).cfgr3.modify(|_, w| w.{modifications});"
+    ));
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+}
+
+/// Constants with the frequency of every clock, for application code
+fn generate_clocks_mod(config: &Config) -> GeneratedString {
+    let mut string = GeneratedString::new();
+
+    string.line("#[allow(dead_code)]");
+    string.line("pub mod clocks {");
+    string.indent_right();
+    for clock in config.clocks.clocks.iter() {
+        string.line(f!("pub const {clock.name}_HZ: u32 = {clock.freq};"));
+    }
+    string.indent_left();
+    string.line("}");
+
+    string
+}
+
+/// The constants defined in CubeMX as Mcu.UserConstants
+fn generate_user_constants_mod(config: &Config) -> GeneratedString {
+    let mut string = GeneratedString::new();
+
+    if config.user_constants.is_empty() {
+        return string;
+    }
+
+    string.line("#[allow(dead_code)]");
+    string.line("pub mod user_constants {");
+    string.indent_right();
+    for constant in config.user_constants.iter() {
+        let rust_type = constant.value.rust_type();
+        let literal = constant.value.literal();
+        string.line(f!("pub const {constant.name}: {rust_type} = {literal};"));
+    }
+    string.indent_left();
+    string.line("}");
+
+    string
+}
+
 fn add_ports(string: &mut GeneratedString, config: &Config) {
     for port in config.ports.iter() {
         let port_lower = port.to_ascii_lowercase();
@@ -114,51 +404,489 @@ fn add_ports(string: &mut GeneratedString, config: &Config) {
     string.empty_line();
 }
 
-fn add_gpios(string: &mut GeneratedString, config: &Config) {
+fn add_gpios(string: &mut GeneratedString, config: &Config) -> anyhow::Result<()> {
+    let mut debug_gpios = Vec::new();
+
     for gpio in config.gpios.iter() {
-        let pin_name = gpio.get_name();
-        let pin_configuration = configure_gpio(gpio, config.mcu_family);
+        if let Some(function) = gpio.get_debug_function(config) {
+            let pin = gpio.register.to_uppercase();
+            if config.delay_debug_pins {
+                debug_gpios.push((gpio, pin, function));
+                continue;
+            }
+            print_warning(f!(
+                "{pin} ({function}) is reconfigured, a debugger can't connect once the program runs, \
+                 use --delay-debug-pins to reconfigure it after a delay"
+            ));
+        }
 
-        let mutable = if !matches!(&gpio.signal, SignalType::Peripheral(_)) {
-            "mut "
-        } else {
-            ""
-        };
+        add_gpio(string, gpio, config)?;
+    }
+
+    if !debug_gpios.is_empty() {
+        let sysclk = get_clock(config, "SYSCLK")?;
+        let pins: Vec<String> = debug_gpios
+            .iter()
+            .map(|(_, pin, function)| f!("{pin} ({function})"))
+            .collect();
+        let pins = pins.join(", ");
 
-        string.line(f!("let {mutable}{pin_name} = {pin_configuration};"));
+        string.empty_line();
+        string.line(f!(
+            "// give a debugger one second to attach before {pins} is reconfigured"
+        ));
+        string.line(f!("cortex_m::asm::delay({sysclk});"));
+        for (gpio, _, _) in debug_gpios {
+            add_gpio(string, gpio, config)?;
+        }
     }
 
     string.empty_line();
+
+    add_gpio_registers(string, config)?;
+
+    Ok(())
+}
+
+fn add_gpio(string: &mut GeneratedString, gpio: &GpioPin, config: &Config) -> anyhow::Result<()> {
+    let pin_name = gpio.get_name();
+    let pin_configuration = configure_gpio(gpio, config)?;
+
+    let mutable = if !matches!(
+        &gpio.signal,
+        SignalType::Peripheral(_)
+            | SignalType::AdcChannel(_)
+            | SignalType::DacOutput(_)
+            | SignalType::Exti(_)
+    ) {
+        "mut "
+    } else {
+        ""
+    };
+
+    // the original label if it had to be changed to be a valid identifier
+    if let Some(label) = gpio.label.as_ref().filter(|&label| *label != pin_name) {
+        string.line(f!("// {label}"));
+    }
+
+    // nothing owns the pin of an event line, the mode only has to be set
+    if matches!(gpio.signal, SignalType::Exti(_))
+        && !gpio.exti_mode.unwrap_or_default().is_interrupt()
+    {
+        string.line(f!("{pin_configuration};"));
+        return Ok(());
+    }
+    string.line(f!("let {mutable}{pin_name} = {pin_configuration};"));
+
+    // CubeMX leaves outputs low unless PinState says otherwise
+    if gpio.signal == SignalType::GpioOutput {
+        match gpio.pin_state {
+            Some(PinStateType::GPIO_PIN_SET) => string.line(f!("{pin_name}.set_high().ok();")),
+            Some(PinStateType::GPIO_PIN_RESET) => string.line(f!("{pin_name}.set_low().ok();")),
+            None => {}
+        }
+    }
+
+    Ok(())
 }
 
-fn configure_gpio(gpio: &GpioPin, mcu_family: MCUFamily) -> String {
-    let name = gpio.get_name();
+/// Arrays of fully erased pins, so applications can iterate over e.g. all LEDs
+fn add_pin_groups(
+    main_func: &mut GeneratedString,
+    imports: &mut GeneratedString,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let mut types = vec!["Pin".to_string()];
 
-    let func = match gpio.signal {
-        SignalType::AdcInput => f!("into_analog"),
-        SignalType::GpioInput => match gpio.pu_pd.unwrap_or_default() {
-            PullType::GPIO_NOPULL => f!("into_floating_input"),
-            PullType::GPIO_PULLUP => f!("into_pull_up_input"),
-            PullType::GPIO_PULLDOWN => f!("into_pull_down_input"),
+    for group in config.pin_groups.iter() {
+        let gpios: Vec<&GpioPin> = group
+            .gpios
+            .iter()
+            .map(|&index| &config.gpios[index])
+            .collect();
+        let (_, mode) = get_pin_mode(gpios[0], config)?;
+        let length = gpios.len();
+
+        // e.g. Output<PushPull> needs Output and PushPull
+        types.extend(
+            mode.split(|c: char| !c.is_ascii_alphanumeric())
+                .filter(|part| !part.is_empty())
+                .map(String::from),
+        );
+
+        main_func.line(f!("let mut {group.name}: [Pin<{mode}>; {length}] = ["));
+        main_func.indent_right();
+        for gpio in gpios {
+            let name = gpio.get_name();
+            main_func.line(f!("{name}.downgrade().downgrade(),"));
+        }
+        main_func.indent_left();
+        main_func.line("];");
+    }
+
+    if config.pin_groups.is_empty() {
+        return Ok(());
+    }
+    main_func.empty_line();
+
+    types.sort();
+    types.dedup();
+    let types = types.join(", ");
+    imports.line(f!("use hal::gpio::{{{types}}};"));
+
+    Ok(())
+}
+
+/// Puts the pins the ioc file doesn't use into analog mode, floating inputs draw current
+fn add_free_pins(string: &mut GeneratedString, config: &Config) {
+    if !config.free_pins_analog {
+        return;
+    }
+    if get_pinout(config).is_none() {
+        print_warning(f!(
+            "No pinout known for {config.mcu_ref}, the free pins aren't put into analog mode"
+        ));
+        return;
+    }
+    if config.free_pins.is_empty() {
+        return;
+    }
+
+    let mut ports: Vec<char> = config.free_pins.iter().map(|&(port, _)| port).collect();
+    ports.dedup();
+
+    string.line("// unused pins as analog to save power");
+    string.line("unsafe {");
+    string.indent_right();
+
+    // the ports used by the GPIOs were enabled by split already
+    let clocks: Vec<String> = ports
+        .iter()
+        .filter(|port| !config.ports.contains(port))
+        .map(|port| f!("iop{}en().set_bit()", port.to_ascii_lowercase()))
+        .collect();
+    if !clocks.is_empty() {
+        let clocks = clocks.join(".");
+        string.line(f!("(*stm32::RCC::ptr()).ahbenr.modify(|_, w| w.{clocks});"));
+    }
+
+    for port in ports {
+        // two MODER bits per pin, 0b11 is analog
+        let mask = config
+            .free_pins
+            .iter()
+            .filter(|&&(other, _)| other == port)
+            .fold(0u32, |mask, &(_, pin)| mask | 0b11 << (2 * pin));
+        string.line(f!(
+            "(*stm32::GPIO{port}::ptr()).moder.modify(|r, w| w.bits(r.bits() | {mask:#010x}));"
+        ));
+    }
+
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+}
+
+fn configure_gpio(gpio: &GpioPin, config: &Config) -> anyhow::Result<String> {
+    let (func, _) = get_pin_mode(gpio, config)?;
+    Ok(f!(
+        "cortex_m::interrupt::free(|cs| {gpio.register}.{func}(cs))"
+    ))
+}
+
+/// The HAL conversion function of a pin and the mode type it results in
+fn get_pin_mode(gpio: &GpioPin, config: &Config) -> anyhow::Result<(String, String)> {
+    let mode = match gpio.signal {
+        SignalType::AdcInput | SignalType::AdcChannel(_) | SignalType::DacOutput(_) => {
+            (f!("into_analog"), f!("Analog"))
+        }
+        SignalType::GpioInput | SignalType::Exti(_) => match gpio.pu_pd.unwrap_or_default() {
+            PullType::GPIO_NOPULL => (f!("into_floating_input"), f!("Input<Floating>")),
+            PullType::GPIO_PULLUP => (f!("into_pull_up_input"), f!("Input<PullUp>")),
+            PullType::GPIO_PULLDOWN => (f!("into_pull_down_input"), f!("Input<PullDown>")),
         },
+        // speed and pull are set afterwards in add_gpio_registers
         SignalType::GpioOutput => match gpio.mode_default_output_pp.unwrap_or_default() {
-            ModeOutputType::GPIO_MODE_OUTPUT_OD => match gpio.speed.unwrap_or_default() {
-                SpeedType::GPIO_SPEED_FREQ_LOW => f!("into_open_drain_output"),
-                _ => todo!("{} higher speeds for", name),
-            },
-            ModeOutputType::GPIO_MODE_OUTPUT_PP => match gpio.speed.unwrap_or_default() {
-                SpeedType::GPIO_SPEED_FREQ_LOW => f!("into_push_pull_output"),
-                SpeedType::GPIO_SPEED_FREQ_MEDIUM => f!("into_push_pull_output_hs"),
-
-                _ => todo!("{} higher speeds for", name),
-            },
+            ModeOutputType::GPIO_MODE_OUTPUT_OD => {
+                (f!("into_open_drain_output"), f!("Output<OpenDrain>"))
+            }
+            ModeOutputType::GPIO_MODE_OUTPUT_PP => {
+                (f!("into_push_pull_output"), f!("Output<PushPull>"))
+            }
         },
         SignalType::Peripheral(ref name) => {
-            let af = get_alternate_function(mcu_family, gpio, name);
-            f!("into_alternate_af{af}")
+            let af = get_alternate_function(config, gpio, name)?;
+            (f!("into_alternate_af{af}"), f!("Alternate<AF{af}>"))
         }
     };
-    f!("interrupt::free(|cs| {gpio.register}.{func}(cs))")
+    Ok(mode)
+}
+
+/// Type aliases for labelled and peripheral pins, e.g. `pub type Out1 = gpiob::PB13<Output<PushPull>>;`
+fn generate_pins_mod(config: &Config) -> anyhow::Result<GeneratedString> {
+    let mut aliases = Vec::new();
+    for gpio in config.gpios.iter() {
+        if gpio.label.is_none() && !matches!(gpio.signal, SignalType::Peripheral(_)) {
+            continue;
+        }
+
+        let alias = to_camel_case(&gpio.get_name());
+        let port_lower = gpio.port.to_lowercase();
+        let pin_type = gpio.register.to_uppercase();
+        let (_, mode) = get_pin_mode(gpio, config)?;
+        // the label only tells something if sanitizing changed it
+        if let Some(label) = &gpio.label {
+            if *label != gpio.get_name() && *label != alias {
+                aliases.push(f!("/// {label}"));
+            }
+        }
+        aliases.push(f!("pub type {alias} = {port_lower}::{pin_type}<{mode}>;"));
+    }
+
+    let mut string = GeneratedString::new();
+    if aliases.is_empty() {
+        return Ok(string);
+    }
+
+    string.line("#[allow(dead_code)]");
+    string.line("pub mod pins {");
+    string.indent_right();
+    string.line("use crate::hal::gpio::*;");
+    string.empty_line();
+    for alias in aliases {
+        string.line(alias);
+    }
+    string.indent_left();
+    string.line("}");
+
+    Ok(string)
+}
+
+/// Output speeds and the pulls of outputs and alternate functions, the HAL only sets them for inputs
+fn add_gpio_registers(string: &mut GeneratedString, config: &Config) -> anyhow::Result<()> {
+    let mut modifications = Vec::new();
+
+    for port in config.ports.iter() {
+        let mut speeds = Vec::new();
+        let mut pulls = Vec::new();
+
+        for gpio in config
+            .gpios
+            .iter()
+            .filter(|gpio| gpio.port.ends_with(*port))
+        {
+            if matches!(
+                gpio.signal,
+                SignalType::GpioInput
+                    | SignalType::AdcInput
+                    | SignalType::AdcChannel(_)
+                    | SignalType::DacOutput(_)
+                    | SignalType::Exti(_)
+            ) {
+                continue;
+            }
+            let pin = &gpio.register[2..];
+
+            match gpio.speed.unwrap_or_default() {
+                // reset value
+                SpeedType::GPIO_SPEED_FREQ_LOW => {}
+                SpeedType::GPIO_SPEED_FREQ_MEDIUM => {
+                    speeds.push(f!("ospeedr{pin}().medium_speed()"))
+                }
+                SpeedType::GPIO_SPEED_FREQ_HIGH => speeds.push(f!("ospeedr{pin}().high_speed()")),
+                SpeedType::GPIO_SPEED_FREQ_VERY_HIGH => bail!(
+                    "{}: GPIO_SPEED_FREQ_VERY_HIGH isn't available on {:?}",
+                    gpio.get_name(),
+                    config.mcu_family
+                ),
+            }
+
+            match gpio.pu_pd.unwrap_or_default() {
+                // reset value
+                PullType::GPIO_NOPULL => {}
+                PullType::GPIO_PULLUP => pulls.push(f!("pupdr{pin}().pull_up()")),
+                PullType::GPIO_PULLDOWN => pulls.push(f!("pupdr{pin}().pull_down()")),
+            }
+        }
+
+        if !speeds.is_empty() {
+            let speeds = speeds.join(".");
+            modifications.push(f!(
+                "(*stm32::GPIO{port}::ptr()).ospeedr.modify(|_, w| w.{speeds});"
+            ));
+        }
+        if !pulls.is_empty() {
+            let pulls = pulls.join(".");
+            modifications.push(f!(
+                "(*stm32::GPIO{port}::ptr()).pupdr.modify(|_, w| w.{pulls});"
+            ));
+        }
+    }
+
+    if modifications.is_empty() {
+        return Ok(());
+    }
+
+    string.line("// output speeds and pulls of outputs aren't configurable through the HAL");
+    string.line("unsafe {");
+    string.indent_right();
+    for modification in modifications {
+        string.line(modification);
+    }
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+
+    Ok(())
+}
+
+/// The pins of EXTI interrupt lines, they're moved into a static so the handler can use them
+fn get_exti_pins(config: &Config) -> Vec<(u8, &GpioPin)> {
+    config
+        .gpios
+        .iter()
+        .filter_map(|gpio| match gpio.signal {
+            SignalType::Exti(line) if gpio.exti_mode.unwrap_or_default().is_interrupt() => {
+                Some((line, gpio))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Maps the EXTI lines to their ports, selects the edges and unmasks the lines,
+/// returns the statics owning the pins of interrupt lines
+fn add_extis(
+    main_func: &mut GeneratedString,
+    imports: &mut GeneratedString,
+    config: &Config,
+) -> GeneratedString {
+    let mut statics = GeneratedString::new();
+
+    let mut exticr: Vec<(u8, Vec<String>)> = Vec::new();
+    let mut rtsr = Vec::new();
+    let mut ftsr = Vec::new();
+    let mut imr = Vec::new();
+    let mut emr = Vec::new();
+
+    for gpio in config.gpios.iter() {
+        let line = match gpio.signal {
+            SignalType::Exti(line) => line,
+            _ => continue,
+        };
+        let mode = gpio.exti_mode.unwrap_or_default();
+
+        // SYSCFG_EXTICR1 holds lines 0 to 3, the port index is 0 for A, 1 for B, etc
+        let register = line / 4 + 1;
+        let port_index = gpio.port.chars().last().unwrap() as u8 - b'A';
+        let field = f!("exti{line}().bits({port_index})");
+        match exticr.iter_mut().find(|(other, _)| *other == register) {
+            Some((_, fields)) => fields.push(field),
+            None => exticr.push((register, vec![field])),
+        }
+
+        if mode.rising() {
+            rtsr.push(f!("tr{line}().set_bit()"));
+        }
+        if mode.falling() {
+            ftsr.push(f!("tr{line}().set_bit()"));
+        }
+        if mode.is_interrupt() {
+            imr.push(f!("mr{line}().set_bit()"));
+        } else {
+            emr.push(f!("mr{line}().set_bit()"));
+        }
+    }
+
+    if exticr.is_empty() {
+        return statics;
+    }
+    exticr.sort_by_key(|(register, _)| *register);
+
+    main_func.line("// EXTI line mapping and edge selection aren't configurable through the HAL");
+    main_func.line("unsafe {");
+    main_func.indent_right();
+    main_func.line("(*stm32::RCC::ptr()).apb2enr.modify(|_, w| w.syscfgen().set_bit());");
+    main_func.line("let syscfg = &*stm32::SYSCFG::ptr();");
+    for (register, fields) in exticr {
+        let fields = fields.join(".");
+        main_func.line(f!("syscfg.exticr{register}.modify(|_, w| w.{fields});"));
+    }
+    main_func.line("let exti = &*stm32::EXTI::ptr();");
+    for (register, fields) in [("rtsr", rtsr), ("ftsr", ftsr), ("imr", imr), ("emr", emr)].iter() {
+        if !fields.is_empty() {
+            let fields = fields.join(".");
+            main_func.line(f!("exti.{register}.modify(|_, w| w.{fields});"));
+        }
+    }
+    main_func.indent_left();
+    main_func.line("}");
+    main_func.empty_line();
+
+    let exti_pins = get_exti_pins(config);
+    if exti_pins.is_empty() {
+        return statics;
+    }
+
+    imports.line("use core::cell::RefCell;");
+    imports.line("use cortex_m::interrupt::Mutex;");
+    imports.empty_line();
+
+    for (_, gpio) in exti_pins {
+        let name = gpio.get_name();
+        let static_name = name.to_uppercase();
+        let port_lower = gpio.port.to_lowercase();
+        let pin_type = gpio.register.to_uppercase();
+        let pull = match gpio.pu_pd.unwrap_or_default() {
+            PullType::GPIO_NOPULL => "Floating",
+            PullType::GPIO_PULLUP => "PullUp",
+            PullType::GPIO_PULLDOWN => "PullDown",
+        };
+
+        statics.line(f!(
+            "static {static_name}: Mutex<RefCell<Option<hal::gpio::{port_lower}::{pin_type}<hal::gpio::Input<hal::gpio::{pull}>>>>> ="
+        ));
+        statics.indent_right();
+        statics.line("Mutex::new(RefCell::new(None));");
+        statics.indent_left();
+
+        main_func.line(f!(
+            "cortex_m::interrupt::free(|cs| {static_name}.borrow(cs).replace(Some({name})));"
+        ));
+    }
+    statics.empty_line();
+    main_func.empty_line();
+
+    statics
+}
+
+/// Body of an EXTI handler, clears the pending bits and borrows the pins
+fn add_exti_handler(handlers: &mut GeneratedString, pins: &[(u8, &GpioPin)]) {
+    handlers.indent_right();
+    handlers.line("cortex_m::interrupt::free(|cs| {");
+    handlers.indent_right();
+    handlers.line("let exti = unsafe { &*stm32::EXTI::ptr() };");
+    for (line, gpio) in pins {
+        let name = gpio.get_name();
+        let static_name = name.to_uppercase();
+
+        handlers.line(f!("if exti.pr.read().pr{line}().bit_is_set() {{"));
+        handlers.indent_right();
+        handlers.line("// writing 1 clears the pending bit");
+        handlers.line(f!("exti.pr.write(|w| w.pr{line}().set_bit());"));
+        handlers.line(f!(
+            "if let Some({name}) = {static_name}.borrow(cs).borrow_mut().as_mut() {{"
+        ));
+        handlers.indent_right();
+        handlers.line(f!("// handle the interrupt of {name} here"));
+        handlers.indent_left();
+        handlers.line("}");
+        handlers.indent_left();
+        handlers.line("}");
+    }
+    handlers.indent_left();
+    handlers.line("});");
+    handlers.indent_left();
 }
 
 fn add_spi(main_func: &mut GeneratedString, imports: &mut GeneratedString, spi: &SPI) {
@@ -193,7 +921,12 @@ fn add_spi(main_func: &mut GeneratedString, imports: &mut GeneratedString, spi:
     main_func.empty_line();
 }
 
-fn add_usart(main_func: &mut GeneratedString, imports: &mut GeneratedString, usart: &USART) {
+fn add_usart(
+    main_func: &mut GeneratedString,
+    imports: &mut GeneratedString,
+    usart: &USART,
+    config: &Config,
+) -> anyhow::Result<()> {
     let baudrate = usart.baudrate.unwrap_or(38400);
 
     imports.line("use hal::serial::Serial;");
@@ -208,10 +941,42 @@ fn add_usart(main_func: &mut GeneratedString, imports: &mut GeneratedString, usa
     main_func.line("&mut rcc");
     main_func.indent_left();
     main_func.line(");");
+
+    // the HAL computes the baud rate from PCLK
+    let pclk = get_clock(config, "PCLK1")?;
+    let kernel_freq = config.clocks.get(&usart.name_upper).unwrap_or(pclk);
+    if kernel_freq != pclk {
+        let brr = (kernel_freq + baudrate / 2) / baudrate;
+        main_func.line(f!(
+            "// {usart.name_upper} runs from {kernel_freq} Hz, not PCLK"
+        ));
+        main_func.line("unsafe {");
+        main_func.indent_right();
+        main_func.line(f!("let usart = &*stm32::{usart.name_upper}::ptr();"));
+        main_func.line("usart.cr1.modify(|_, w| w.ue().clear_bit());");
+        main_func.line(f!("usart.brr.write(|w| w.bits({brr}));"));
+        main_func.line("usart.cr1.modify(|_, w| w.ue().set_bit());");
+        main_func.indent_left();
+        main_func.line("}");
+    }
+
     main_func.empty_line();
+    Ok(())
 }
 
-fn add_i2c(main_func: &mut GeneratedString, imports: &mut GeneratedString, i2c: &I2C) {
+fn get_clock(config: &Config, name: &str) -> anyhow::Result<u32> {
+    config
+        .clocks
+        .get(name)
+        .ok_or_else(|| anyhow!("{} isn't part of the clock tree", name))
+}
+
+fn add_i2c(
+    main_func: &mut GeneratedString,
+    imports: &mut GeneratedString,
+    i2c: &I2C,
+    config: &Config,
+) -> anyhow::Result<()> {
     imports.line("use hal::i2c::I2c;");
 
     let speed: u32 = match i2c.mode.unwrap_or_default() {
@@ -228,7 +993,323 @@ fn add_i2c(main_func: &mut GeneratedString, imports: &mut GeneratedString, i2c:
     main_func.line("&mut rcc");
     main_func.indent_left();
     main_func.line(");");
+
+    // the HAL calculates the timing for the 8 MHz HSI, anything else has to be written
+    let kernel_freq = get_clock(config, &i2c.name_upper)?;
+    let timingr = match i2c.timing {
+        Some(timing) => timing,
+        None if kernel_freq == HSI_FREQ => {
+            main_func.empty_line();
+            return Ok(());
+        }
+        None => get_timingr(kernel_freq, i2c.mode.unwrap_or_default())
+            .with_context(|| f!("Timing of {i2c.name_upper}"))?,
+    };
+    main_func.line("unsafe {");
+    main_func.indent_right();
+    main_func.line(f!("let i2c = &*stm32::{i2c.name_upper}::ptr();"));
+    main_func.line("i2c.cr1.modify(|_, w| w.pe().clear_bit());");
+    main_func.line(f!("i2c.timingr.write(|w| w.bits({timingr:#010X}));"));
+    main_func.line("i2c.cr1.modify(|_, w| w.pe().set_bit());");
+    main_func.indent_left();
+    main_func.line("}");
+
     main_func.empty_line();
+    Ok(())
+}
+
+fn add_adc(
+    main_func: &mut GeneratedString,
+    imports: &mut GeneratedString,
+    adc: &ADC,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let sample_time = match adc.sampling_time.unwrap_or_default() {
+        SamplingTime::ADC_SAMPLETIME_1CYCLE_5 => "T_1",
+        SamplingTime::ADC_SAMPLETIME_7CYCLES_5 => "T_7",
+        SamplingTime::ADC_SAMPLETIME_13CYCLES_5 => "T_13",
+        SamplingTime::ADC_SAMPLETIME_28CYCLES_5 => "T_28",
+        SamplingTime::ADC_SAMPLETIME_41CYCLES_5 => "T_41",
+        SamplingTime::ADC_SAMPLETIME_55CYCLES_5 => "T_55",
+        SamplingTime::ADC_SAMPLETIME_71CYCLES_5 => "T_71",
+        SamplingTime::ADC_SAMPLETIME_239CYCLES_5 => "T_239",
+    };
+
+    let precision = match adc.resolution.unwrap_or_default() {
+        Resolution::ADC_RESOLUTION_12B => "B_12",
+        Resolution::ADC_RESOLUTION_10B => "B_10",
+        Resolution::ADC_RESOLUTION_8B => "B_8",
+        Resolution::ADC_RESOLUTION_6B => "B_6",
+    };
+
+    let align = match adc.data_align.unwrap_or_default() {
+        DataAlign::ADC_DATAALIGN_RIGHT => "Right",
+        DataAlign::ADC_DATAALIGN_LEFT => "Left",
+    };
+
+    let mut types = vec!["Adc", "AdcAlign", "AdcPrecision", "AdcSampleTime"];
+
+    // the HAL defaults to 239.5 cycles, CubeMX to 1.5 cycles
+    main_func.line("let mut adc = Adc::new(p.ADC, &mut rcc);");
+    main_func.line(f!("adc.set_sample_time(AdcSampleTime::{sample_time});"));
+    main_func.line(f!("adc.set_precision(AdcPrecision::{precision});"));
+    main_func.line(f!("adc.set_align(AdcAlign::{align});"));
+
+    if !adc.channels.is_empty() {
+        let mut sensors = Vec::new();
+
+        main_func.line("let mut adc_channels = AdcChannels {");
+        main_func.indent_right();
+        for channel in adc.channels.iter() {
+            let name = channel.get_name(config);
+            let sensor = match channel {
+                AdcChannel::Input(_) => {
+                    main_func.line(f!("{name},"));
+                    continue;
+                }
+                AdcChannel::TempSensor => "VTemp",
+                AdcChannel::VRefInt => "VRef",
+                AdcChannel::VBat => "VBat",
+            };
+            main_func.line(f!("{name}: {sensor}::new(),"));
+            types.push(sensor);
+            sensors.push(name);
+        }
+        main_func.indent_left();
+        main_func.line("};");
+
+        // the internal channels are switched off after reset
+        for name in sensors {
+            main_func.line(f!("adc_channels.{name}.enable(&mut adc);"));
+        }
+    }
+
+    let types = types.join(", ");
+    imports.line(f!("use hal::adc::{{{types}}};"));
+
+    main_func.empty_line();
+    Ok(())
+}
+
+fn add_dac(
+    main_func: &mut GeneratedString,
+    imports: &mut GeneratedString,
+    dac: &DAC,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let mut names = Vec::new();
+    for channel in dac.channels.iter() {
+        let gpio = config
+            .gpios
+            .iter()
+            .find(|gpio| gpio.signal == SignalType::DacOutput(channel.channel))
+            .ok_or_else(|| anyhow!("No pin has the DAC_OUT{} signal", channel.channel))?;
+        names.push(gpio.get_name());
+    }
+
+    imports.line("use hal::dac::{dac, DacPin};");
+
+    // the HAL turns the analog pins into the channels, they keep the pin names
+    let outputs: Vec<String> = names.iter().map(|name| f!("mut {name}")).collect();
+    let (outputs, pins) = match names.len() {
+        1 => (outputs[0].clone(), names[0].clone()),
+        _ => (f!("({})", outputs.join(", ")), f!("({})", names.join(", "))),
+    };
+    main_func.line(f!("let {outputs} = dac(p.DAC, {pins}, &mut rcc);"));
+
+    // the HAL only enables the channels
+    let mut modifications = Vec::new();
+    for channel in dac.channels.iter() {
+        let n = channel.channel;
+
+        if channel.output_buffer.unwrap_or_default() == OutputBuffer::DAC_OUTPUTBUFFER_DISABLE {
+            modifications.push(f!("boff{n}().set_bit()"));
+        }
+
+        if let Some(tsel) = channel.trigger.unwrap_or_default().tsel() {
+            modifications.push(f!("ten{n}().set_bit()"));
+            modifications.push(f!("tsel{n}().bits({tsel:#05b})"));
+        }
+
+        let wave = match channel.wave_generation.unwrap_or_default() {
+            WaveGeneration::DAC_WAVEGENERATION_NONE => continue,
+            WaveGeneration::DAC_WAVEGENERATION_NOISE => "0b01",
+            WaveGeneration::DAC_WAVEGENERATION_TRIANGLE => "0b10",
+        };
+        let amplitude = channel.amplitude.unwrap_or_default();
+        modifications.push(f!("wave{n}().bits({wave})"));
+        modifications.push(f!("mamp{n}().bits({amplitude})"));
+    }
+
+    if !modifications.is_empty() {
+        let modifications = modifications.join(".");
+        main_func.line("unsafe {");
+        main_func.indent_right();
+        main_func.line("let dac = &*stm32::DAC::ptr();");
+        main_func.line(f!("dac.cr.modify(|_, w| w.{modifications});"));
+        main_func.indent_left();
+        main_func.line("}");
+    }
+
+    for name in names {
+        main_func.line(f!("{name}.enable();"));
+    }
+
+    main_func.empty_line();
+    Ok(())
+}
+
+/// The channels of the ADC with a blocking read for each of them
+fn generate_adc_channels(config: &Config) -> anyhow::Result<GeneratedString> {
+    let mut string = GeneratedString::new();
+
+    let channels = match &config.adc {
+        Some(adc) if !adc.channels.is_empty() => &adc.channels,
+        _ => return Ok(string),
+    };
+
+    let mut fields = Vec::new();
+    for channel in channels.iter() {
+        let name = channel.get_name(config);
+        let channel_type = match channel {
+            AdcChannel::Input(number) => {
+                let gpio = channel
+                    .get_gpio(config)
+                    .ok_or_else(|| anyhow!("No pin has the ADC_IN{} signal", number))?;
+                let port_lower = gpio.port.to_lowercase();
+                let pin_type = gpio.register.to_uppercase();
+                f!("hal::gpio::{port_lower}::{pin_type}<hal::gpio::Analog>")
+            }
+            AdcChannel::TempSensor => "hal::adc::VTemp".to_string(),
+            AdcChannel::VRefInt => "hal::adc::VRef".to_string(),
+            AdcChannel::VBat => "hal::adc::VBat".to_string(),
+        };
+        fields.push((name, channel_type));
+    }
+
+    string.line("/// The channels converted by the ADC, ordered by their rank");
+    string.line("pub struct AdcChannels {");
+    string.indent_right();
+    for (name, channel_type) in fields.iter() {
+        string.line(f!("pub {name}: {channel_type},"));
+    }
+    string.indent_left();
+    string.line("}");
+    string.empty_line();
+
+    string.line("#[allow(dead_code)]");
+    string.line("impl AdcChannels {");
+    string.indent_right();
+    for (index, (name, _)) in fields.iter().enumerate() {
+        if index > 0 {
+            string.empty_line();
+        }
+        string.line(f!(
+            "pub fn read_{name}(&mut self, adc: &mut hal::adc::Adc) -> u16 {{"
+        ));
+        string.indent_right();
+        string.line(f!("adc.read(&mut self.{name}).unwrap()"));
+        string.indent_left();
+        string.line("}");
+    }
+    string.indent_left();
+    string.line("}");
+
+    Ok(string)
+}
+
+/// Unmasks the enabled interrupts and returns empty handlers for them
+fn add_interrupts(
+    main_func: &mut GeneratedString,
+    imports: &mut GeneratedString,
+    config: &Config,
+) -> anyhow::Result<GeneratedString> {
+    let mut handlers = GeneratedString::new();
+
+    let vectors = get_vectors(config)?;
+
+    let mut enabled = Vec::new();
+    for interrupt in config.interrupts.iter() {
+        if !interrupt.enabled || interrupt.is_core_exception() {
+            continue;
+        }
+        let vector = get_vector(vectors, &interrupt.name).ok_or_else(|| {
+            anyhow!(
+                "{} has no interrupt vector {}",
+                config.mcu_name,
+                interrupt.name
+            )
+        })?;
+        enabled.push((interrupt.preemption_priority, vector));
+    }
+
+    // EXTI interrupt lines need their vector even if it isn't enabled in the NVIC section
+    let exti_pins = get_exti_pins(config);
+    for (line, _) in exti_pins.iter() {
+        let cube_name = get_exti_vector(config.mcu_family, *line);
+        let vector = get_vector(vectors, cube_name)
+            .ok_or_else(|| anyhow!("{} has no interrupt vector {}", config.mcu_name, cube_name))?;
+        if !enabled
+            .iter()
+            .any(|(_, other)| other.number == vector.number)
+        {
+            enabled.push((0, vector));
+        }
+    }
+
+    enabled.sort_by_key(|(_, vector)| vector.number);
+
+    if enabled.is_empty() {
+        return Ok(handlers);
+    }
+
+    // the cortex-m0 NVIC only implements the upper two priority bits
+    let priority_shift = match config.mcu_family {
+        MCUFamily::STM32F0 => 6,
+        _ => todo!("other NVIC priority bits"),
+    };
+
+    imports.line("use crate::hal::stm32::interrupt;");
+
+    if enabled.iter().any(|&(priority, _)| priority != 0) {
+        main_func.line("let mut cp = stm32::CorePeripherals::take().unwrap();");
+    }
+
+    main_func.line("unsafe {");
+    main_func.indent_right();
+
+    for (priority, vector) in enabled {
+        if priority != 0 {
+            main_func.line(f!(
+                "cp.NVIC.set_priority(interrupt::{vector.pac_name}, {priority} << {priority_shift});"
+            ));
+        }
+        main_func.line(f!("stm32::NVIC::unmask(interrupt::{vector.pac_name});"));
+
+        let vector_pins: Vec<(u8, &GpioPin)> = exti_pins
+            .iter()
+            .filter(|(line, _)| get_exti_vector(config.mcu_family, *line) == vector.cube_name)
+            .copied()
+            .collect();
+
+        handlers.empty_line();
+        handlers.line("#[interrupt]");
+        if vector_pins.is_empty() {
+            handlers.line(f!("fn {vector.pac_name}() {{}}"));
+        } else {
+            // the pins are only bound for the code the user adds
+            handlers.line("#[allow(unused_variables)]");
+            handlers.line(f!("fn {vector.pac_name}() {{"));
+            add_exti_handler(&mut handlers, &vector_pins);
+            handlers.line("}");
+        }
+    }
+
+    main_func.indent_left();
+    main_func.line("}");
+    main_func.empty_line();
+
+    Ok(handlers)
 }
 
 pub fn generate_cargo_config(config: &Config) -> String {
@@ -300,13 +1381,21 @@ panic-halt = "*"
 }
 
 pub fn generate_memory_x(config: &Config) -> anyhow::Result<String> {
-    let mem_size = get_mem_size(config);
-
-    Ok(f!("\
-MEMORY
-{{
-  FLASH : ORIGIN = 0x00000000, LENGTH = {mem_size.flash}K
-  RAM : ORIGIN = 0x20000000, LENGTH = {mem_size.ram}K
-}}
-"))
+    let mem_regions = get_mem_regions(config)?;
+
+    let mut file_content = String::from("MEMORY\n{\n");
+
+    for region in mem_regions {
+        let length = if region.length % 1024 == 0 {
+            f!("{}K", region.length / 1024)
+        } else {
+            region.length.to_string()
+        };
+        file_content.push_str(&f!(
+            "  {region.name} : ORIGIN = {region.origin:#010X}, LENGTH = {length}\n"
+        ));
+    }
+
+    file_content.push_str("}\n");
+    Ok(file_content)
 }
@@ -2,6 +2,7 @@ use std::convert::TryFrom;
 
 use regex::Regex;
 
+use crate::db::*;
 use crate::*;
 
 #[derive(Debug)]
@@ -14,8 +15,22 @@ pub struct GpioPin {
     pub speed: Option<SpeedType>,
     pub pu_pd: Option<PullType>,
     pub mode_default_output_pp: Option<ModeOutputType>,
+    pub exti_mode: Option<ExtiModeType>,
 }
 
+/// Signals of SYS in Serial Wire, JTAG and trace mode
+pub const DEBUG_SIGNALS: [&str; 9] = [
+    "SYS_SWDIO",
+    "SYS_SWCLK",
+    "SYS_JTMS-SWDIO",
+    "SYS_JTCK-SWCLK",
+    "SYS_JTDI",
+    "SYS_JTDO-TRACESWO",
+    "SYS_JTDO-SWO",
+    "SYS_JNTRST",
+    "SYS_TRACESWO",
+];
+
 pub fn get_gpios(config: &ConfigParams<'_>) -> anyhow::Result<(Vec<char>, Vec<GpioPin>)> {
     // regex matches PA11, PB4, etc
     let re = Regex::new(r"^P[A-K]\d{1,2}").unwrap();
@@ -37,9 +52,14 @@ pub fn get_gpios(config: &ConfigParams<'_>) -> anyhow::Result<(Vec<char>, Vec<Gp
     for (name, parameters) in gpio_params {
         let gpio: GpioPin = GpioPin::new(name, parameters).context(format!("Pin: {}", name))?;
 
-        // Don't count external clock sources as GPIOs
+        // Don't count external clock sources and debug pins as GPIOs,
+        // the debug pins are in the right alternate function after reset
         if let SignalType::Peripheral(ref signal) = gpio.signal {
-            if signal == "RCC_OSC_OUT" || signal == "RCC_OSC_IN" {
+            if matches!(
+                signal.as_str(),
+                "RCC_OSC_IN" | "RCC_OSC_OUT" | "RCC_OSC32_IN" | "RCC_OSC32_OUT"
+            ) || DEBUG_SIGNALS.contains(&signal.as_str())
+            {
                 continue;
             }
         };
@@ -53,6 +73,312 @@ pub fn get_gpios(config: &ConfigParams<'_>) -> anyhow::Result<(Vec<char>, Vec<Gp
     Ok((ports, gpios))
 }
 
+/// Checks the pins used in the ioc file against the pinout of the MCU package
+pub fn validate_pins(config_params: &ConfigParams<'_>, config: &Config) -> anyhow::Result<()> {
+    let pinout = match get_pinout(config) {
+        Some(pinout) => pinout,
+        None => {
+            print_warning(f!(
+                "No pinout known for {config.mcu_ref}, pins are not validated, \
+                 pinoutparse.py generates the pinouts from the CubeMX database"
+            ));
+            return Ok(());
+        }
+    };
+
+    ensure!(
+        pinout.package == config.mcu_package,
+        "Mcu.Package={} doesn't match the package {} of {}",
+        config.mcu_package,
+        pinout.package,
+        config.mcu_ref
+    );
+
+    // regex matches PA11, PB4, etc
+    let re = Regex::new(r"^P[A-K]\d{1,2}").unwrap();
+
+    let used_pins = get_used_pins(config_params);
+
+    let mut errors = Vec::new();
+    let mut assignments: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut signals: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for &name in used_pins.iter() {
+        // CubeMX isn't consistent with the oscillator pin names, e.g. PC14-OSC32_IN and PC14OSC32_IN
+        let pin = pinout.pins.iter().find(|pin| pin.name == name).or_else(|| {
+            let port_pin = re.find(name)?.as_str();
+            pinout
+                .pins
+                .iter()
+                .find(|pin| re.find(pin.name).map(|m| m.as_str()) == Some(port_pin))
+        });
+
+        let pin = match pin {
+            Some(pin) => pin,
+            None => {
+                errors.push(f!("{name} doesn't exist on package {pinout.package}"));
+                continue;
+            }
+        };
+
+        let signal = config_params
+            .get(name)
+            .and_then(|parameters| parameters.get("Signal"));
+
+        if let Some(&signal) = signal {
+            if pin.pin_type != PinType::Io {
+                errors.push(f!(
+                    "{name} (pin {pin.position}) is a {pin.pin_type:?} pin and can't be used as {signal}"
+                ));
+            }
+
+            if !signal.starts_with("GPIO_") {
+                signals.entry(signal).or_default().push(name);
+            }
+        }
+
+        assignments.entry(pin.name).or_default().push(name);
+    }
+
+    let mut duplicates: Vec<String> = assignments
+        .iter()
+        .filter(|(_, names)| names.len() > 1)
+        .map(|(pin, names)| f!("{pin} is assigned more than once: {}", names.join(", ")))
+        .chain(
+            signals
+                .iter()
+                .filter(|(_, names)| names.len() > 1)
+                .map(|(signal, names)| {
+                    f!(
+                        "{signal} is assigned to more than one pin: {}",
+                        names.join(", ")
+                    )
+                }),
+        )
+        .collect();
+    duplicates.sort_by(|a, b| human_sort::compare(a, b));
+    errors.append(&mut duplicates);
+
+    ensure!(errors.is_empty(), errors.join("\n"));
+
+    Ok(())
+}
+
+/// The pins listed as Mcu.Pin<n> and the ones with a signal assigned, virtual pins excluded
+fn get_used_pins<'a>(config_params: &ConfigParams<'a>) -> Vec<&'a str> {
+    let mut used_pins: Vec<&str> = Vec::new();
+    if let Some(mcu) = config_params.get("Mcu") {
+        for (parameter, &pin) in mcu {
+            if parameter.starts_with("Pin") && parameter[3..].parse::<u32>().is_ok() {
+                used_pins.push(pin);
+            }
+        }
+    }
+    for (&name, parameters) in config_params {
+        if parameters.contains_key("Signal") && !used_pins.contains(&name) {
+            used_pins.push(name);
+        }
+    }
+    used_pins.retain(|pin| !pin.starts_with("VP_"));
+    used_pins.sort_by(|a, b| human_sort::compare(a, b));
+    used_pins
+}
+
+/// The I/O pins of the package that aren't used in the ioc file, e.g. ('A', 3) for PA3
+pub fn get_free_pins(config_params: &ConfigParams<'_>, config: &Config) -> Vec<(char, u8)> {
+    let pinout = match get_pinout(config) {
+        Some(pinout) => pinout,
+        None => return Vec::new(),
+    };
+
+    // regex matches PA11, PB4, etc, also in PC14-OSC32_IN
+    let re = Regex::new(r"^P([A-K])(\d{1,2})").unwrap();
+    let port_pin = |name: &str| -> Option<(char, u8)> {
+        let captures = re.captures(name)?;
+        Some((captures[1].chars().next()?, captures[2].parse().ok()?))
+    };
+
+    let used_pins: Vec<(char, u8)> = get_used_pins(config_params)
+        .into_iter()
+        .filter_map(port_pin)
+        .collect();
+
+    // the oscillator pins and the debug pins after reset keep their function,
+    // e.g. PC14OSC32_IN and PA14 with SWCLK
+    let mut free_pins: Vec<(char, u8)> = pinout
+        .pins
+        .iter()
+        .filter(|pin| pin.pin_type == PinType::Io && !pin.name.contains("OSC"))
+        .filter(|pin| get_reset_debug_signal(config, &pin.name.to_lowercase()).is_none())
+        .filter_map(|pin| port_pin(pin.name))
+        .filter(|pin| !used_pins.contains(pin))
+        .collect();
+    free_pins.sort_unstable();
+    free_pins.dedup();
+
+    free_pins
+}
+
+/// Numbered pins of the same mode, e.g. LED_0 to LED_7, they're collected into an array
+#[derive(Debug)]
+pub struct PinGroup {
+    /// plural of the common label, e.g. leds
+    pub name: String,
+    /// indices into Config.gpios, ordered by the number in the label
+    pub gpios: Vec<usize>,
+}
+
+pub fn get_pin_groups(gpios: &[GpioPin]) -> Vec<PinGroup> {
+    // matches led_0, dip3, etc
+    let re = Regex::new(r"^(.*?[a-z])_?(\d+)$").unwrap();
+
+    let mut candidates: Vec<(String, Vec<(u32, usize)>)> = Vec::new();
+    for (index, gpio) in gpios.iter().enumerate() {
+        // peripheral, ADC, DAC and EXTI pins are owned by their driver or handler
+        if gpio.label.is_none()
+            || !matches!(
+                gpio.signal,
+                SignalType::GpioInput | SignalType::GpioOutput | SignalType::AdcInput
+            )
+        {
+            continue;
+        }
+
+        let name = gpio.get_name();
+        let captures = match re.captures(&name) {
+            Some(captures) => captures,
+            None => continue,
+        };
+        let base = captures[1].to_string();
+        let number = match captures[2].parse() {
+            Ok(number) => number,
+            Err(_) => continue,
+        };
+
+        match candidates.iter_mut().find(|(other, _)| *other == base) {
+            Some((_, members)) => members.push((number, index)),
+            None => candidates.push((base, vec![(number, index)])),
+        }
+    }
+
+    let mut groups = Vec::new();
+    for (base, mut members) in candidates {
+        members.sort_by_key(|&(number, _)| number);
+
+        let first = &gpios[members[0].1];
+        let same_mode = members
+            .iter()
+            .all(|&(_, index)| gpios[index].has_same_mode(first));
+        let distinct_numbers = members.windows(2).all(|pair| pair[0].0 != pair[1].0);
+
+        if members.len() > 1 && same_mode && distinct_numbers {
+            groups.push(PinGroup {
+                name: f!("{base}s"),
+                gpios: members.iter().map(|&(_, index)| index).collect(),
+            });
+        }
+    }
+
+    groups
+}
+
+/// Variables every generated main.rs uses
+const RESERVED_NAMES: [&str; 3] = ["p", "rcc", "cp"];
+
+/// Checks that the names generated from the labels don't clash with each other,
+/// with the peripherals, or with the port bindings like pb13
+pub fn validate_names(config: &Config) -> anyhow::Result<()> {
+    let mut names: Vec<(String, String)> = RESERVED_NAMES
+        .iter()
+        .map(|&name| (name.to_string(), f!("the generated variable {name}")))
+        .collect();
+
+    let peripherals = config
+        .spis
+        .iter()
+        .map(|spi| (&spi.name_lower, &spi.name_upper))
+        .chain(
+            config
+                .usarts
+                .iter()
+                .map(|usart| (&usart.name_lower, &usart.name_upper)),
+        )
+        .chain(
+            config
+                .i2cs
+                .iter()
+                .map(|i2c| (&i2c.name_lower, &i2c.name_upper)),
+        );
+    for (name_lower, name_upper) in peripherals {
+        names.push((name_lower.clone(), f!("the {name_upper} peripheral")));
+    }
+    if config.adc.is_some() {
+        names.push(("adc".to_string(), "the ADC peripheral".to_string()));
+        names.push((
+            "adc_channels".to_string(),
+            "the generated ADC channels".to_string(),
+        ));
+    }
+    if config.dac.is_some() {
+        names.push((
+            "dac".to_string(),
+            "the imported DAC constructor".to_string(),
+        ));
+    }
+
+    let mut errors = Vec::new();
+    let mut aliases: HashMap<String, &GpioPin> = HashMap::new();
+
+    for gpio in config.gpios.iter() {
+        let name = gpio.get_name();
+        let description = gpio.describe();
+
+        match names.iter().find(|(other, _)| *other == name) {
+            Some((_, other)) => {
+                errors.push(f!("{description} and {other} both use the name {name}"));
+            }
+            None => names.push((name.clone(), description.clone())),
+        }
+
+        // the port bindings are shadowed by the converted pins, only the own one may be reused
+        if let Some(other) = config
+            .gpios
+            .iter()
+            .find(|other| other.register == name && other.register != gpio.register)
+        {
+            errors.push(f!(
+                "{description} uses the name {name} of the port binding of {}",
+                other.register.to_uppercase()
+            ));
+        }
+
+        // e.g. out_1 and out1 both become the pin alias Out1
+        if gpio.label.is_some() || matches!(gpio.signal, SignalType::Peripheral(_)) {
+            let alias = to_camel_case(&name);
+            if let Some(other) = aliases.insert(alias.clone(), gpio) {
+                if other.get_name() != name {
+                    errors.push(f!(
+                        "{} and {description} both become the pin alias {alias}",
+                        other.describe()
+                    ));
+                }
+            }
+        }
+    }
+
+    for group in config.pin_groups.iter() {
+        if let Some((_, other)) = names.iter().find(|(other, _)| *other == group.name) {
+            errors.push(f!(
+                "the pin group {group.name} and {other} both use the name {group.name}"
+            ));
+        }
+    }
+
+    ensure!(errors.is_empty(), errors.join("\n"));
+
+    Ok(())
+}
+
 impl GpioPin {
     pub fn new(name: &str, parameters: &HashMap<&str, &str>) -> anyhow::Result<Self> {
         let (port, register) = parse_name(name)?;
@@ -65,6 +391,7 @@ impl GpioPin {
 
         let speed = parse_optional_param(parameters, "GPIO_Speed")?;
         let mode_default_output_pp = parse_optional_param(parameters, "GPIO_ModeDefaultOutputPP")?;
+        let exti_mode = parse_optional_param(parameters, "GPIO_ModeDefaultEXTI")?;
 
         Ok(GpioPin {
             port,
@@ -75,19 +402,65 @@ impl GpioPin {
             speed,
             pu_pd,
             mode_default_output_pp,
+            exti_mode,
         })
     }
 
+    /// The variable name, the label as snake_case identifier if there is a usable one
     pub fn get_name(&self) -> String {
         let register = &self.register;
 
+        if let Some(name) = self.label.as_deref().and_then(to_identifier) {
+            return name;
+        }
+
+        match self.signal {
+            SignalType::Peripheral(ref name) => name.to_lowercase(),
+            SignalType::AdcInput => f!("adc_{register}"),
+            SignalType::AdcChannel(channel) => f!("adc_in{channel}"),
+            SignalType::DacOutput(channel) => f!("dac_out{channel}"),
+            SignalType::Exti(_) => f!("exti_{register}"),
+            _ => f!("gpio_{register}"),
+        }
+    }
+
+    /// The alternate function number of a peripheral pin, e.g. 1 for USART1_TX on PA9
+    pub fn get_alternate_function(&self, config: &Config) -> anyhow::Result<Option<u8>> {
+        match &self.signal {
+            SignalType::Peripheral(name) => get_alternate_function(config, self, name).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// The debug function this pin has after reset and takes away, e.g. SWDIO for PA13
+    pub fn get_debug_function(&self, config: &Config) -> Option<&'static str> {
+        get_reset_debug_signal(config, &self.register)
+            .map(|signal| signal.trim_start_matches("SYS_"))
+    }
+
+    /// Whether both pins are converted into the same type
+    fn has_same_mode(&self, other: &GpioPin) -> bool {
+        if self.signal != other.signal {
+            return false;
+        }
+        match self.signal {
+            SignalType::GpioInput => {
+                self.pu_pd.unwrap_or_default() == other.pu_pd.unwrap_or_default()
+            }
+            SignalType::GpioOutput => {
+                self.mode_default_output_pp.unwrap_or_default()
+                    == other.mode_default_output_pp.unwrap_or_default()
+            }
+            _ => true,
+        }
+    }
+
+    /// e.g. PB13 (GPIO_Label=LED Red)
+    fn describe(&self) -> String {
+        let pin = self.register.to_uppercase();
         match &self.label {
-            Some(label) => label.clone(),
-            None => match self.signal {
-                SignalType::Peripheral(ref name) => name.to_lowercase(),
-                SignalType::AdcInput => f!("adc_{register}"),
-                _ => f!("gpio_{register}"),
-            },
+            Some(label) => f!("{pin} (GPIO_Label={label})"),
+            None => pin,
         }
     }
 }
@@ -112,6 +485,12 @@ pub enum SignalType {
     GpioInput,
     GpioOutput,
     AdcInput,
+    /// input channel of the ADC, e.g. 1 for ADC_IN1
+    AdcChannel(u8),
+    /// output channel of the DAC, e.g. 1 for DAC_OUT1
+    DacOutput(u8),
+    /// external interrupt line
+    Exti(u8),
     Peripheral(String),
 }
 
@@ -123,6 +502,33 @@ impl TryFrom<&str> for SignalType {
             "GPIO_Input" => Ok(SignalType::GpioInput),
             "GPIO_Output" => Ok(SignalType::GpioOutput),
             "GPIO_Analog" => Ok(SignalType::AdcInput),
+            _ if text.starts_with("ADC_IN") => {
+                let channel = text["ADC_IN".len()..]
+                    .parse()
+                    .map_err(|_| anyhow!("could not parse ADC channel of {}", text))?;
+                Ok(SignalType::AdcChannel(channel))
+            }
+            // CubeMX names the F0 DAC outputs COMP_DAC1_group and COMP_DAC2_group
+            _ if text.starts_with("DAC_OUT") || text.starts_with("COMP_DAC") => {
+                let channel = text
+                    .trim_start_matches("DAC_OUT")
+                    .trim_start_matches("COMP_DAC")
+                    .trim_end_matches("_group");
+                let channel = channel
+                    .parse()
+                    .map_err(|_| anyhow!("could not parse DAC channel of {}", text))?;
+                Ok(SignalType::DacOutput(channel))
+            }
+            // CubeMX writes GPXTI13 and refers to it as GPIO_EXTI13 in the SH section
+            _ if text.starts_with("GPXTI") || text.starts_with("GPIO_EXTI") => {
+                let line = text
+                    .trim_start_matches("GPXTI")
+                    .trim_start_matches("GPIO_EXTI");
+                let line = line
+                    .parse()
+                    .map_err(|_| anyhow!("could not parse EXTI line of {}", text))?;
+                Ok(SignalType::Exti(line))
+            }
             _ => Ok(SignalType::Peripheral(String::from(text))),
         }
     }
@@ -139,6 +545,45 @@ parameter!(
     [GPIO_MODE_OUTPUT_OD, GPIO_MODE_OUTPUT_PP],
     default = GPIO_MODE_OUTPUT_PP
 );
+parameter!(
+    ExtiModeType,
+    [
+        GPIO_MODE_IT_RISING,
+        GPIO_MODE_IT_FALLING,
+        GPIO_MODE_IT_RISING_FALLING,
+        GPIO_MODE_EVT_RISING,
+        GPIO_MODE_EVT_FALLING,
+        GPIO_MODE_EVT_RISING_FALLING
+    ],
+    default = GPIO_MODE_IT_RISING
+);
+
+impl ExtiModeType {
+    /// interrupt modes set EXTI_IMR, event modes EXTI_EMR
+    pub fn is_interrupt(self) -> bool {
+        matches!(
+            self,
+            ExtiModeType::GPIO_MODE_IT_RISING
+                | ExtiModeType::GPIO_MODE_IT_FALLING
+                | ExtiModeType::GPIO_MODE_IT_RISING_FALLING
+        )
+    }
+
+    pub fn rising(self) -> bool {
+        !matches!(
+            self,
+            ExtiModeType::GPIO_MODE_IT_FALLING | ExtiModeType::GPIO_MODE_EVT_FALLING
+        )
+    }
+
+    pub fn falling(self) -> bool {
+        !matches!(
+            self,
+            ExtiModeType::GPIO_MODE_IT_RISING | ExtiModeType::GPIO_MODE_EVT_RISING
+        )
+    }
+}
+
 parameter!(
     SpeedType,
     [
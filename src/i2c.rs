@@ -6,6 +6,8 @@ pub struct I2C {
     pub name_lower: String,
     pub name_upper: String,
     pub mode: Option<Mode>,
+    /// I2C_TIMINGR as CubeMX calculated it for the kernel clock
+    pub timing: Option<u32>,
 }
 
 pub fn get_i2cs(config: &ConfigParams<'_>) -> anyhow::Result<Vec<I2C>> {
@@ -22,15 +24,24 @@ pub fn get_i2cs(config: &ConfigParams<'_>) -> anyhow::Result<Vec<I2C>> {
                 let name_upper = String::from(captures.get(1).unwrap().as_str());
                 let name_lower = name_upper.to_ascii_lowercase();
                 let mut mode = None;
+                let mut timing = None;
 
                 if let Some(i2c_params) = config.get::<str>(&name_upper) {
                     mode = parse_optional_param(i2c_params, "I2C_Speed_Mode")?;
+                    timing = i2c_params
+                        .get("Timing")
+                        .map(|s| {
+                            u32::from_str_radix(s.trim_start_matches("0x"), 16)
+                                .map_err(|_| anyhow!(f!("{name_upper}.Timing invalid hex value")))
+                        })
+                        .transpose()?;
                 }
 
                 i2cs.push(I2C {
                     name_lower,
                     name_upper,
                     mode,
+                    timing,
                 });
             }
         };
@@ -39,6 +50,41 @@ pub fn get_i2cs(config: &ConfigParams<'_>) -> anyhow::Result<Vec<I2C>> {
     Ok(i2cs)
 }
 
+/// Computes I2C_TIMINGR for the kernel clock, scaling the reference
+/// timings of RM0091 for an 8 MHz I2CCLK to the prescaled clock
+pub fn get_timingr(kernel_freq: u32, mode: Mode) -> anyhow::Result<u32> {
+    // timebase, SCLDEL, SDADEL, SCLH, SCLL
+    let (timebase, scldel, sdadel, sclh, scll): (u32, u32, u32, u32, u32) = match mode {
+        Mode::I2C_Standard => (4_000_000, 0x4, 0x2, 0xF, 0x13),
+        Mode::I2C_Fast => (8_000_000, 0x3, 0x1, 0x3, 0x9),
+        Mode::I2C_Fast_Plus => (8_000_000, 0x1, 0x0, 0x3, 0x6),
+    };
+
+    // the smallest prescaler that doesn't tick faster than the timebase
+    let presc = (kernel_freq + timebase - 1) / timebase - 1;
+    ensure!(
+        presc <= 0xF,
+        "I2C clock of {} Hz is too fast for {:?}",
+        kernel_freq,
+        mode
+    );
+
+    // rounds up so the periods don't get shorter than the reference ones
+    let scale = |ticks: u32| {
+        let scaled = u64::from(ticks) * u64::from(kernel_freq);
+        let divisor = u64::from(timebase) * u64::from(presc + 1);
+        ((scaled + divisor - 1) / divisor) as u32
+    };
+
+    // the SCLDEL, SCLH and SCLL fields count one tick more than their value
+    let scldel = scale(scldel + 1).max(1) - 1;
+    let sdadel = scale(sdadel);
+    let sclh = scale(sclh + 1).max(1) - 1;
+    let scll = scale(scll + 1).max(1) - 1;
+
+    Ok(presc << 28 | scldel << 20 | sdadel << 16 | sclh << 8 | scll)
+}
+
 parameter!(
     Mode,
     [I2C_Standard, I2C_Fast, I2C_Fast_Plus],
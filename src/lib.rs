@@ -21,9 +21,25 @@
 //! $ cube2rust path/to/project_directory
 //! ```
 //!
+//! Render the clock tree as `clock_tree.txt`, `clock_tree.dot` and `clock_tree.svg`
+//! instead of generating a project
+//! ```bash
+//! $ cube2rust report path/to/project_directory
+//! ```
+//!
+//! Unused pins are put into analog mode to save power if "Set all free pins as analog" is checked in CubeMX or with
+//! ```bash
+//! $ cube2rust --free-pins-analog
+//! ```
+//!
+//! Pins the debugger uses after reset, e.g. PA13 and PA14 for SWD, are only reconfigured after a one second delay with
+//! ```bash
+//! $ cube2rust --delay-debug-pins
+//! ```
+//!
 //! # Currently supported
 //! * Only STM32F0
-//! * GPIO, RCC, SPI, USART, I2C
+//! * GPIO, RCC, SPI, USART, I2C, NVIC, ADC, DAC
 
 #![warn(rust_2018_idioms)]
 
@@ -32,13 +48,20 @@ extern crate fstrings;
 
 #[macro_use]
 mod utils;
+mod adc;
+mod clocks;
+mod dac;
 mod db;
+mod dma;
 mod generate;
 mod gpio;
 mod i2c;
+mod nvic;
 mod rcc;
+mod report;
 mod spi;
 mod usart;
+mod user_constants;
 
 use std::collections::HashMap;
 use std::fs;
@@ -49,11 +72,17 @@ use std::process::Command;
 
 use anyhow::{anyhow, bail, ensure, Context};
 
-use crate::gpio::GpioPin;
+use crate::adc::ADC;
+use crate::clocks::ClockTree;
+use crate::dac::DAC;
+use crate::dma::DMA;
+use crate::gpio::{GpioPin, PinGroup};
 use crate::i2c::I2C;
+use crate::nvic::Interrupt;
 use crate::rcc::RCC;
 use crate::spi::SPI;
 use crate::usart::USART;
+use crate::user_constants::UserConstant;
 use crate::utils::*;
 
 type ConfigParams<'a> = HashMap<&'a str, HashMap<&'a str, &'a str>>;
@@ -64,12 +93,27 @@ pub struct Config {
     pub version: String,
     pub mcu_family: MCUFamily,
     pub mcu_name: String,
+    pub mcu_ref: String,
+    pub mcu_package: String,
     pub rcc: RCC,
+    pub clocks: ClockTree,
     pub gpios: Vec<GpioPin>,
+    pub pin_groups: Vec<PinGroup>,
+    /// pins of the package the ioc file doesn't use, e.g. ('A', 3) for PA3
+    pub free_pins: Vec<(char, u8)>,
+    /// set the free pins to analog mode to save power, from ProjectManager.FreePins
+    pub free_pins_analog: bool,
+    /// reconfigure pins the debugger uses only after a delay, so a debugger can still attach
+    pub delay_debug_pins: bool,
     pub ports: Vec<char>,
     pub spis: Vec<SPI>,
     pub usarts: Vec<USART>,
     pub i2cs: Vec<I2C>,
+    pub adc: Option<ADC>,
+    pub dac: Option<DAC>,
+    pub dmas: Vec<DMA>,
+    pub interrupts: Vec<Interrupt>,
+    pub user_constants: Vec<UserConstant>,
 }
 
 /// Loads a project configuration from the ioc file content
@@ -90,35 +134,95 @@ pub fn load_ioc(file_content: &str) -> anyhow::Result<Config> {
 
     let mcu_family = parse_mandatory_param(mcu, "Family")?;
 
-    let mcu_name = mcu
-        .get("UserName")
-        .ok_or_else(|| anyhow!("Couldn't check MCU name"))?
+    // Mcu.Name can describe a range of MCUs, e.g. STM32F042C(4-6)Tx
+    let mcu_ref = mcu
+        .get("Name")
+        .ok_or_else(|| anyhow!("Couldn't check MCU reference"))?
+        .to_string();
+
+    let mcu_name = db::resolve_mcu_name(mcu_family, mcu.get("UserName").copied(), &mcu_ref)
+        .context("Resolving the MCU name")?
         .to_string();
 
-    let rcc = rcc::get_rcc(&config_params).context("Parsing of RCC")?;
+    let mcu_package = mcu
+        .get("Package")
+        .ok_or_else(|| anyhow!("Couldn't check MCU package"))?
+        .to_string();
+
+    let rcc = rcc::get_rcc(&config_params, mcu_family).context("Parsing of RCC")?;
+
+    let clocks =
+        clocks::get_clock_tree(&rcc, mcu_family, &mcu_name).context("Computing the clock tree")?;
 
     let (ports, gpios) = gpio::get_gpios(&config_params).context("Parsing of GPIOs")?;
 
+    let pin_groups = gpio::get_pin_groups(&gpios);
+
+    let free_pins_analog = config_params
+        .get("ProjectManager")
+        .and_then(|project_manager| project_manager.get("FreePins"))
+        == Some(&"true");
+
     let spis = spi::get_spis(&config_params).context("Parsing of SPIs")?;
 
     let usarts = usart::get_usarts(&config_params).context("Parsing of USARTs")?;
 
     let i2cs = i2c::get_i2cs(&config_params).context("Parsing of I2Cs")?;
 
-    Ok(Config {
+    let adc = adc::get_adc(&config_params).context("Parsing of ADC")?;
+
+    let dac = dac::get_dac(&config_params).context("Parsing of DAC")?;
+
+    let dmas = dma::get_dmas(&config_params).context("Parsing of DMAs")?;
+
+    let interrupts = nvic::get_interrupts(&config_params).context("Parsing of NVIC")?;
+
+    let user_constants =
+        user_constants::get_user_constants(&config_params).context("Parsing of user constants")?;
+
+    let mut config = Config {
         version,
         mcu_family,
         mcu_name,
+        mcu_ref,
+        mcu_package,
         rcc,
+        clocks,
         gpios,
+        pin_groups,
+        free_pins: Vec::new(),
+        free_pins_analog,
+        delay_debug_pins: false,
         ports,
         spis,
         usarts,
         i2cs,
-    })
+        adc,
+        dac,
+        dmas,
+        interrupts,
+        user_constants,
+    };
+
+    // needs the pinout of the package
+    config.free_pins = gpio::get_free_pins(&config_params, &config);
+
+    rcc::validate_rcc(&config).context("Validation of RCC")?;
+    clocks::validate_clock_tree(&config_params, &config).context("Validation of the clock tree")?;
+    gpio::validate_pins(&config_params, &config).context("Validation of pins")?;
+    gpio::validate_names(&config).context("Validation of names")?;
+    adc::validate_adc(&config).context("Validation of ADC")?;
+    dac::validate_dac(&config).context("Validation of DAC")?;
+    dma::validate_dmas(&config).context("Validation of DMAs")?;
+    nvic::validate_interrupts(&config).context("Validation of NVIC")?;
+
+    Ok(config)
 }
 
 /// Parses the ioc file content into nested HashMaps
+///
+/// Keys with more than two parts are split at the first dot,
+/// e.g. Dma.USART1_TX.0.Instance becomes Dma -> USART1_TX.0.Instance
 pub fn parse_ioc(file_content: &str) -> ConfigParams<'_> {
     let mut config_params = HashMap::new();
 
@@ -126,7 +230,7 @@ pub fn parse_ioc(file_content: &str) -> ConfigParams<'_> {
         let name_and_value: Vec<&str> = line.split('=').collect();
 
         if let [name, value] = name_and_value[..] {
-            let object_and_parameter: Vec<&str> = name.split('.').collect();
+            let object_and_parameter: Vec<&str> = name.splitn(2, '.').collect();
             if let [object_name, parameter_name] = object_and_parameter[..] {
                 config_params
                     .entry(object_name)
@@ -180,7 +284,7 @@ pub fn generate(project_dir: &Path, config: Config) -> anyhow::Result<()> {
     }
 
     // src/main.rs
-    let main_rs = generate::generate_main(&config)?;
+    let main_rs = generate_main(&config)?;
     println!("Generated src/main.rs");
 
     let path_to_main = project_dir.join("src/main.rs");
@@ -203,3 +307,27 @@ pub fn generate(project_dir: &Path, config: Config) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Generates the content of src/main.rs from the given configuration
+pub fn generate_main(config: &Config) -> anyhow::Result<String> {
+    generate::generate_main(config)
+}
+
+/// Writes the clock tree as a text table, a Graphviz DOT graph and an SVG
+pub fn report(project_dir: &Path, config: &Config) -> anyhow::Result<()> {
+    let table = report::generate_clock_table(config);
+    fs::write(project_dir.join("clock_tree.txt"), &table).context("write to clock_tree.txt")?;
+    println!("Generated clock_tree.txt");
+
+    let dot = report::generate_clock_dot(config);
+    fs::write(project_dir.join("clock_tree.dot"), dot).context("write to clock_tree.dot")?;
+    println!("Generated clock_tree.dot");
+
+    let svg = report::generate_clock_svg(config);
+    fs::write(project_dir.join("clock_tree.svg"), svg).context("write to clock_tree.svg")?;
+    println!("Generated clock_tree.svg");
+
+    println!("\n{}", table);
+
+    Ok(())
+}
@@ -9,16 +9,14 @@ fn get_path_to_ioc_file(dir: &Path) -> anyhow::Result<PathBuf> {
     for entry in dir.read_dir()? {
         let entry = entry?;
         match entry.file_name().to_str() {
-            None => {}
-            Some(filename) => {
-                if filename.ends_with(".ioc") {
-                    if path_to_ioc_file.is_none() {
-                        path_to_ioc_file = Some(entry.path());
-                    } else {
-                        return Err(anyhow!("More than one .ioc file"));
-                    }
+            Some(filename) if filename.ends_with(".ioc") => {
+                if path_to_ioc_file.is_none() {
+                    path_to_ioc_file = Some(entry.path());
+                } else {
+                    return Err(anyhow!("More than one .ioc file"));
                 }
             }
+            _ => {}
         }
     }
 
@@ -26,7 +24,14 @@ fn get_path_to_ioc_file(dir: &Path) -> anyhow::Result<PathBuf> {
 }
 
 fn run() -> anyhow::Result<()> {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+    let free_pins_analog = args.iter().any(|arg| arg == "--free-pins-analog");
+    let delay_debug_pins = args.iter().any(|arg| arg == "--delay-debug-pins");
+    args.retain(|arg| arg != "--free-pins-analog" && arg != "--delay-debug-pins");
+    let report = args.get(1).map(String::as_str) == Some("report");
+    if report {
+        args.remove(1);
+    }
     let default_path = String::new();
     let project_dir = Path::new(args.get(1).unwrap_or(&default_path));
 
@@ -35,10 +40,16 @@ fn run() -> anyhow::Result<()> {
     println!("Found ioc file {:?}", path_to_ioc_file.file_name().unwrap());
     let filecontent = fs::read_to_string(path_to_ioc_file)?;
 
-    let config = cube2rust::load_ioc(&filecontent)?;
+    let mut config = cube2rust::load_ioc(&filecontent)?;
+    config.free_pins_analog |= free_pins_analog;
+    config.delay_debug_pins |= delay_debug_pins;
     println!("Loaded ioc file");
 
-    cube2rust::generate(project_dir, config)
+    if report {
+        cube2rust::report(project_dir, &config)
+    } else {
+        cube2rust::generate(project_dir, config)
+    }
 }
 
 fn main() -> anyhow::Result<()> {
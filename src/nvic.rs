@@ -0,0 +1,84 @@
+use crate::db::*;
+use crate::*;
+
+/// Cortex-M exceptions, they are configured in the NVIC section but aren't part of the PAC
+const CORE_EXCEPTIONS: [&str; 5] = [
+    "NonMaskableInt_IRQn",
+    "HardFault_IRQn",
+    "SVC_IRQn",
+    "PendSV_IRQn",
+    "SysTick_IRQn",
+];
+
+#[derive(Debug)]
+pub struct Interrupt {
+    /// CubeMX name, e.g. USART1_IRQn
+    pub name: String,
+    pub enabled: bool,
+    pub preemption_priority: u8,
+    pub sub_priority: u8,
+}
+
+impl Interrupt {
+    pub fn is_core_exception(&self) -> bool {
+        CORE_EXCEPTIONS.contains(&self.name.as_str())
+    }
+}
+
+pub fn get_interrupts(config: &ConfigParams<'_>) -> anyhow::Result<Vec<Interrupt>> {
+    let mut interrupts = Vec::new();
+
+    let nvic_params = match config.get("NVIC") {
+        Some(nvic_params) => nvic_params,
+        None => return Ok(interrupts),
+    };
+
+    for (&name, &value) in nvic_params.iter() {
+        if !name.ends_with("_IRQn") {
+            continue;
+        }
+
+        // e.g. NVIC.USART1_IRQn=true\:0\:0\:false\:false\:true\:true\:true
+        // enabled, preemption priority, sub priority, followed by code generation flags
+        let fields: Vec<&str> = value.split("\\:").collect();
+        let (enabled, preemption_priority, sub_priority) = match fields[..] {
+            [enabled, preemption_priority, sub_priority, ..] => {
+                (enabled, preemption_priority, sub_priority)
+            }
+            _ => bail!("Couldn't parse NVIC.{}={}", name, value),
+        };
+
+        interrupts.push(Interrupt {
+            name: name.to_string(),
+            enabled: enabled.parse()?,
+            preemption_priority: preemption_priority.parse()?,
+            sub_priority: sub_priority.parse()?,
+        });
+    }
+
+    interrupts.sort_by(|a, b| human_sort::compare(&a.name, &b.name));
+
+    Ok(interrupts)
+}
+
+/// Checks that every enabled interrupt exists in the vector table of the MCU
+pub fn validate_interrupts(config: &Config) -> anyhow::Result<()> {
+    let vectors = get_vectors(config)?;
+
+    let unknown: Vec<&str> = config
+        .interrupts
+        .iter()
+        .filter(|interrupt| interrupt.enabled && !interrupt.is_core_exception())
+        .filter(|interrupt| get_vector(vectors, &interrupt.name).is_none())
+        .map(|interrupt| interrupt.name.as_str())
+        .collect();
+
+    ensure!(
+        unknown.is_empty(),
+        "{} has no interrupt vector {}",
+        config.mcu_name,
+        unknown.join(", ")
+    );
+
+    Ok(())
+}
@@ -1,6 +1,30 @@
+use crate::db::*;
 use crate::*;
 
-pub fn get_rcc(config: &ConfigParams<'_>) -> anyhow::Result<RCC> {
+/// HSI frequency, the ioc file doesn't list SYSCLKFreq_VALUE for it
+pub const HSI_FREQ: u32 = 8_000_000;
+/// reset value of HSITRIM, CubeMX's default HSICalibrationValue
+pub const HSI_CALIBRATION_RESET: u32 = 16;
+/// HSITRIM is a 5 bit field
+const HSI_CALIBRATION_MAX: u32 = 31;
+/// the AHB prescaler skips /32
+const AHB_DIVIDERS: [u32; 9] = [1, 2, 4, 8, 16, 64, 128, 256, 512];
+const APB1_DIVIDERS: [u32; 5] = [1, 2, 4, 8, 16];
+/// CubeMX's CRS defaults, 48 MHz / 1 kHz USB SOF - 1
+const CRS_RELOAD_USB: u32 = 47_999;
+const CRS_ERROR_LIMIT_DEFAULT: u32 = 34;
+const CRS_HSI48_CALIBRATION_DEFAULT: u32 = 32;
+/// widths of CRS_CFGR.RELOAD, CRS_CFGR.FELIM and CRS_CR.TRIM
+const CRS_RELOAD_MAX: u32 = 0xFFFF;
+const CRS_ERROR_LIMIT_MAX: u32 = 0xFF;
+const CRS_HSI48_CALIBRATION_MAX: u32 = 0x3F;
+/// USB needs exactly 48 MHz
+const USB_FREQ: u32 = 48_000_000;
+/// CubeMX's default LSE_VALUE and LSI_VALUE
+const LSE_FREQ: u32 = 32_768;
+const LSI_FREQ: u32 = 40_000;
+
+pub fn get_rcc(config: &ConfigParams<'_>, mcu_family: MCUFamily) -> anyhow::Result<RCC> {
     let rcc_params = config
         .get("RCC")
         .ok_or_else(|| anyhow!("No RCC configuration found"))?;
@@ -12,65 +36,567 @@ pub fn get_rcc(config: &ConfigParams<'_>) -> anyhow::Result<RCC> {
     let sysclk_freq = parse_optional_u32(rcc_params, "SYSCLKFreq_VALUE")?;
     let hclk_freq = parse_optional_u32(rcc_params, "HCLKFreq_Value")?;
     let apb1_freq = parse_optional_u32(rcc_params, "APB1Freq_Value")?;
+    let pll_freq = parse_optional_u32(rcc_params, "PLLCLKFreq_Value")?;
+    let pll_mul = parse_optional_param(rcc_params, "PLLMUL")?;
+    let prediv = parse_optional_param(rcc_params, "PREDIV")?;
+    let ahb_divider = parse_optional_param(rcc_params, "AHBCLKDivider")?;
+    let apb1_divider = parse_optional_param(rcc_params, "APB1CLKDivider")?;
+    let flash_latency = parse_optional_param(rcc_params, "FLatency")?;
+    let hsi_calibration = parse_optional_u32(rcc_params, "HSICalibrationValue")?;
+    // sic, CubeMX spells it this way
+    let css_enabled = rcc_params.get("EnbaleCSS") == Some(&"ENABLE");
+
+    let clock_source = get_clock_source(&sys_clock_source, &pll_clock_source, config, mcu_family)?;
+    let hse_value_default = !rcc_params.contains_key("HSE_VALUE");
 
-    let clock_source = get_clock_source(&sys_clock_source, &pll_clock_source, config)?;
+    // low speed clocks
+    let lse = get_lse(config)?;
+    let lsi_freq = parse_optional_u32(rcc_params, "LSI_VALUE")?.unwrap_or(LSI_FREQ);
+    let rtc_clock_source = parse_optional_param(rcc_params, "RTCClockSelection")?;
+
+    // USB
+    let usb_used = config.contains_key("USB") || find_pin_with_signal(config, "USB_DP").is_some();
+    let usb_clock_source = if usb_used {
+        Some(parse_optional_param(rcc_params, "USBClockSelection")?.unwrap_or_default())
+    } else {
+        None
+    };
+    let crs = get_crs(rcc_params)?;
+
+    let kernel_clocks = get_kernel_clocks(rcc_params)?;
+
+    // clock output, only if a pin has the RCC_MCO signal
+    let mco = match find_pin_with_signal(config, "RCC_MCO") {
+        Some(_) => Some(MCO {
+            source: parse_optional_param(rcc_params, "RCC_MCOSource")?.unwrap_or_default(),
+            divider: parse_optional_param(rcc_params, "RCC_MCODiv")?.unwrap_or_default(),
+        }),
+        None => None,
+    };
 
     Ok(RCC {
         clock_source,
+        hse_value_default,
+        sys_clock_source,
+        pll_clock_source,
         sysclk_freq,
         hclk_freq,
         apb1_freq,
+        pll_freq,
+        pll_mul,
+        prediv,
+        ahb_divider,
+        apb1_divider,
+        flash_latency,
+        hsi_calibration,
+        css_enabled,
+        lse,
+        lsi_freq,
+        rtc_clock_source,
+        usb_clock_source,
+        crs,
+        mco,
+        kernel_clocks,
     })
 }
 
+/// The kernel clock switches set in the ioc file, the others stay at their reset selection
+fn get_kernel_clocks(rcc_params: &HashMap<&str, &str>) -> anyhow::Result<Vec<KernelClock>> {
+    let mut kernel_clocks = Vec::new();
+
+    for switch in KERNEL_CLOCK_SWITCHES.iter() {
+        let &value = match rcc_params.get(switch.ioc_key) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        // e.g. RCC_USART1CLKSOURCE_SYSCLK
+        let source = switch
+            .sources
+            .iter()
+            .find(|(name, _, _)| value == f!("RCC_{switch.peripheral}CLKSOURCE_{name}"))
+            .map(|&(_, source, _)| source)
+            .ok_or_else(|| anyhow!("invalid RCC.{} {}", switch.ioc_key, value))?;
+
+        kernel_clocks.push(KernelClock { switch, source });
+    }
+
+    Ok(kernel_clocks)
+}
+
+/// The clock recovery system trims HSI48 to a synchronization signal
+fn get_crs(rcc_params: &HashMap<&str, &str>) -> anyhow::Result<Option<CRS>> {
+    let sync_source = match parse_optional_param(rcc_params, "CRSActivatedSource")? {
+        Some(sync_source) => sync_source,
+        None => return Ok(None),
+    };
+
+    Ok(Some(CRS {
+        sync_source,
+        polarity: parse_optional_param(rcc_params, "CRSPolarity")?.unwrap_or_default(),
+        prescaler: parse_optional_param(rcc_params, "CRSPrescaler")?.unwrap_or_default(),
+        reload: parse_optional_u32(rcc_params, "CRSReloadValue")?.unwrap_or(CRS_RELOAD_USB),
+        error_limit: parse_optional_u32(rcc_params, "CRSErrorLimitValue")?
+            .unwrap_or(CRS_ERROR_LIMIT_DEFAULT),
+        hsi48_calibration: parse_optional_u32(rcc_params, "CRSHSI48CalibrationValue")?
+            .unwrap_or(CRS_HSI48_CALIBRATION_DEFAULT),
+    }))
+}
+
+/// Finds the pin that has the given signal assigned, e.g. PC14-OSC32_IN for RCC_OSC32_IN
+pub fn find_pin_with_signal<'a>(
+    config: &'a ConfigParams<'_>,
+    signal: &str,
+) -> Option<(&'a str, &'a HashMap<&'a str, &'a str>)> {
+    config
+        .iter()
+        .find(|(_, params)| params.get("Signal") == Some(&signal))
+        .map(|(&name, params)| (name, params))
+}
+
+fn get_lse(config: &ConfigParams<'_>) -> anyhow::Result<Option<LSE>> {
+    // RCC existance was checked already
+    let rcc_params = config.get("RCC").unwrap();
+
+    let (name, pin_params) = match find_pin_with_signal(config, "RCC_OSC32_IN") {
+        Some(pin) => pin,
+        None => return Ok(None),
+    };
+
+    let bypass = match pin_params.get("Mode") {
+        Some(&"LSE-External-Oscillator") => false,
+        Some(&"LSE-External-Clock-Source") => true,
+        Some(mode) => bail!("Unknown LSE mode {}.Mode={}", name, mode),
+        None => bail!("{}.Mode required", name),
+    };
+
+    Ok(Some(LSE {
+        bypass,
+        drive: parse_optional_param(rcc_params, "LSE_Drive_Capability")?.unwrap_or_default(),
+        freq: parse_optional_u32(rcc_params, "LSE_VALUE")?.unwrap_or(LSE_FREQ),
+    }))
+}
+
 fn get_clock_source(
     sys_clock_source: &Option<SYSCLKSourceType>,
     pll_clock_source: &Option<PLLSourceType>,
     config: &ConfigParams<'_>,
+    mcu_family: MCUFamily,
 ) -> anyhow::Result<ClockSource> {
+    let hse = || -> anyhow::Result<ClockSource> {
+        let hse_mode = get_hse_mode(config, mcu_family)?
+            .ok_or_else(|| anyhow!("HSE is selected but no pin has the RCC_OSC_IN signal"))?;
+        Ok(ClockSource::HSE(hse_mode))
+    };
+
     match sys_clock_source {
         None | Some(SYSCLKSourceType::RCC_SYSCLKSOURCE_HSI) => Ok(ClockSource::HSI),
         Some(SYSCLKSourceType::RCC_SYSCLKSOURCE_HSI48) => Ok(ClockSource::HSI48),
-        Some(SYSCLKSourceType::RCC_SYSCLKSOURCE_HSE) => Ok(ClockSource::HSE(get_hse_mode(config)?)),
+        Some(SYSCLKSourceType::RCC_SYSCLKSOURCE_HSE) => hse(),
         Some(SYSCLKSourceType::RCC_SYSCLKSOURCE_PLLCLK) => match pll_clock_source {
             None | Some(PLLSourceType::RCC_PLLSOURCE_HSI) => Ok(ClockSource::HSI),
             Some(PLLSourceType::RCC_PLLSOURCE_HSI48) => Ok(ClockSource::HSI48),
-            Some(PLLSourceType::RCC_PLLSOURCE_HSE) => Ok(ClockSource::HSE(get_hse_mode(config)?)),
+            Some(PLLSourceType::RCC_PLLSOURCE_HSE) => hse(),
         },
     }
 }
 
-fn get_hse_mode(config: &ConfigParams<'_>) -> anyhow::Result<HSEMode> {
+/// Finds the oscillator pin by its signal, it's PF0 on most F0 packages,
+/// but e.g. PD0 or PH0 on other families
+fn get_hse_mode(
+    config: &ConfigParams<'_>,
+    mcu_family: MCUFamily,
+) -> anyhow::Result<Option<HSEMode>> {
     // RCC existance was checked already
     let rcc_params = config.get("RCC").unwrap();
 
-    // get mode from pin configuration
-    let &mode = config
-        .get("PF0-OSC_IN")
-        .map(|pfo| pfo.get("Mode"))
-        .ok_or_else(|| anyhow!("PF0-OSC_IN required"))?
-        .ok_or_else(|| anyhow!("PF0-OSC_IN.Mode required"))?;
-    // get freq
-    let freq = parse_mandatory_u32(rcc_params, "VCOOutput2Freq_Value")?;
-
-    let mode: anyhow::Result<HSEMode> = match mode {
-        "HSE-External-Oscillator" => Ok(HSEMode::NotBypassed(freq)),
-        "HSE-External-Clock-Source" => Ok(HSEMode::Bypassed(freq)),
-        _ => bail!("Unknown clock source"),
+    let (name, pin_params) = match find_pin_with_signal(config, "RCC_OSC_IN") {
+        Some(pin) => pin,
+        None => return Ok(None),
+    };
+
+    // CubeMX leaves out HSE_VALUE if it's the default of the family
+    let freq = parse_optional_u32(rcc_params, "HSE_VALUE")?
+        .unwrap_or(get_clock_limits(mcu_family).hse_default);
+
+    let mode = match pin_params.get("Mode") {
+        Some(&"HSE-External-Oscillator") => HSEMode::NotBypassed(freq),
+        Some(&"HSE-External-Clock-Source") => HSEMode::Bypassed(freq),
+        Some(mode) => bail!("Unknown HSE mode {}.Mode={}", name, mode),
+        None => bail!("{}.Mode required", name),
     };
-    mode.context("Parsing of external clock source")
+    Ok(Some(mode))
 }
 
+/// Checks the clock tree against the limits of the MCU family,
+/// errors name the offending ioc key
+pub fn validate_rcc(config: &Config) -> anyhow::Result<()> {
+    let limits = get_clock_limits(config.mcu_family);
+    let rcc = &config.rcc;
+    let mut errors = Vec::new();
+
+    let mut check_max = |key: &str, freq: u32, max: u32| {
+        if freq > max {
+            errors.push(f!("RCC.{key}={freq} exceeds the maximum of {max} Hz"));
+        }
+    };
+
+    let sysclk_freq = rcc.sysclk_freq.unwrap_or(HSI_FREQ);
+    let hclk_freq = rcc.hclk_freq.unwrap_or(sysclk_freq);
+    let apb1_freq = rcc.apb1_freq.unwrap_or(hclk_freq);
+
+    check_max("SYSCLKFreq_VALUE", sysclk_freq, limits.sysclk_max);
+    check_max("HCLKFreq_Value", hclk_freq, limits.hclk_max);
+    check_max("APB1Freq_Value", apb1_freq, limits.apb1_max);
+
+    if !is_divided_by(sysclk_freq, hclk_freq, &AHB_DIVIDERS) {
+        errors.push(f!(
+            "RCC.HCLKFreq_Value={hclk_freq} can't be reached from SYSCLK {sysclk_freq} Hz"
+        ));
+    }
+    if !is_divided_by(hclk_freq, apb1_freq, &APB1_DIVIDERS) {
+        errors.push(f!(
+            "RCC.APB1Freq_Value={apb1_freq} can't be reached from HCLK {hclk_freq} Hz"
+        ));
+    }
+
+    if let ClockSource::HSE(hse_mode) = &rcc.clock_source {
+        let (freq, range) = match hse_mode {
+            HSEMode::NotBypassed(freq) => (freq, &limits.hse),
+            HSEMode::Bypassed(freq) => (freq, &limits.hse_bypass),
+        };
+        if !range.contains(freq) {
+            // CubeMX leaves out HSE_VALUE if it's the default of the family
+            let value = if rcc.hse_value_default {
+                f!("the {config.mcu_family:?} default HSE_VALUE {freq}")
+            } else {
+                f!("RCC.HSE_VALUE={freq}")
+            };
+            errors.push(f!("{value} is outside the HSE range {range:?} Hz"));
+        }
+    }
+
+    if rcc.sys_clock_source == Some(SYSCLKSourceType::RCC_SYSCLKSOURCE_PLLCLK) {
+        let pll_freq = rcc.pll_freq.unwrap_or(sysclk_freq);
+        if !limits.pll_output.contains(&pll_freq) {
+            errors.push(f!(
+                "RCC.PLLCLKFreq_Value={pll_freq} is outside the PLL output range {limits.pll_output:?} Hz"
+            ));
+        }
+        if let Some(pll_mul) = rcc.pll_mul {
+            let pll_input = pll_freq / pll_mul.factor();
+            if !limits.pll_input.contains(&pll_input) {
+                errors.push(f!(
+                    "RCC.PLLMUL={pll_mul:?} gives a PLL input of {pll_input} Hz, outside the range {limits.pll_input:?} Hz"
+                ));
+            }
+        }
+    }
+
+    match (rcc.rtc_clock_source, &rcc.lse) {
+        (Some(RTCClockSource::RCC_RTCCLKSOURCE_LSE), None) => errors.push(f!(
+            "RCC.RTCClockSelection=RCC_RTCCLKSOURCE_LSE but no pin has the RCC_OSC32_IN signal"
+        )),
+        (Some(RTCClockSource::RCC_RTCCLKSOURCE_HSE_DIV32), _)
+            if !matches!(rcc.clock_source, ClockSource::HSE(_)) =>
+        {
+            errors.push(f!(
+                "RCC.RTCClockSelection=RCC_RTCCLKSOURCE_HSE_DIV32 but HSE isn't configured"
+            ))
+        }
+        _ => {}
+    }
+
+    if let Some(usb_clock_source) = rcc.usb_clock_source {
+        let usb_freq = config.clocks.get("USB").unwrap_or_default();
+        if usb_freq != USB_FREQ {
+            errors.push(f!(
+                "RCC.USBClockSelection={usb_clock_source:?} gives {usb_freq} Hz, USB needs {USB_FREQ} Hz"
+            ));
+        } else if !rcc.is_usb_clock_accurate() {
+            print_warning(f!(
+                "The USB clock {usb_clock_source:?} isn't accurate enough for USB, \
+                 use HSE or synchronize HSI48 with the CRS"
+            ));
+        }
+    }
+
+    if let Some(crs) = &rcc.crs {
+        if crs.reload > CRS_RELOAD_MAX {
+            errors.push(f!(
+                "RCC.CRSReloadValue={crs.reload} exceeds the maximum of {CRS_RELOAD_MAX}"
+            ));
+        }
+        if crs.error_limit > CRS_ERROR_LIMIT_MAX {
+            errors.push(f!(
+                "RCC.CRSErrorLimitValue={crs.error_limit} exceeds the maximum of {CRS_ERROR_LIMIT_MAX}"
+            ));
+        }
+        if crs.hsi48_calibration > CRS_HSI48_CALIBRATION_MAX {
+            errors.push(f!(
+                "RCC.CRSHSI48CalibrationValue={crs.hsi48_calibration} exceeds the maximum of {CRS_HSI48_CALIBRATION_MAX}"
+            ));
+        }
+    }
+
+    for kernel_clock in rcc.kernel_clocks.iter() {
+        if kernel_clock.source == KernelClockSource::LSE && rcc.lse.is_none() {
+            errors.push(f!(
+                "RCC.{kernel_clock.switch.ioc_key} selects LSE but no pin has the RCC_OSC32_IN signal"
+            ));
+        }
+    }
+
+    if let Some(mco) = &rcc.mco {
+        let available = match mco.source {
+            MCOSource::RCC_MCO1SOURCE_LSE => rcc.lse.is_some(),
+            MCOSource::RCC_MCO1SOURCE_HSE => matches!(rcc.clock_source, ClockSource::HSE(_)),
+            _ => true,
+        };
+        if !available {
+            errors.push(f!(
+                "RCC.RCC_MCOSource={mco.source:?} but the clock isn't configured"
+            ));
+        }
+    }
+
+    if let Some(hsi_calibration) = rcc.hsi_calibration {
+        if hsi_calibration > HSI_CALIBRATION_MAX {
+            errors.push(f!(
+                "RCC.HSICalibrationValue={hsi_calibration} exceeds the maximum of {HSI_CALIBRATION_MAX}"
+            ));
+        }
+    }
+
+    let wait_states = limits
+        .flash_wait_states
+        .iter()
+        .position(|&max| hclk_freq <= max);
+    match (wait_states, rcc.flash_latency) {
+        (None, _) => {} // already reported as exceeding the HCLK maximum
+        (Some(wait_states), Some(flash_latency)) if flash_latency.wait_states() < wait_states => {
+            errors.push(f!(
+                "RCC.FLatency={flash_latency:?} is too low for HCLK {hclk_freq} Hz, {wait_states} wait states needed"
+            ))
+        }
+        _ => {}
+    }
+
+    ensure!(errors.is_empty(), errors.join("\n"));
+
+    Ok(())
+}
+
+/// whether freq can be divided down to divided_freq by one of the prescaler settings
+fn is_divided_by(freq: u32, divided_freq: u32, dividers: &[u32]) -> bool {
+    divided_freq != 0 && freq % divided_freq == 0 && dividers.contains(&(freq / divided_freq))
+}
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
 pub struct RCC {
-    // TODO USBClockSource
-    // TODO CRS
     pub clock_source: ClockSource,
+    /// HSE_VALUE was left out, the HSE frequency is the family default
+    pub hse_value_default: bool,
+    pub sys_clock_source: Option<SYSCLKSourceType>,
+    pub pll_clock_source: Option<PLLSourceType>,
     pub sysclk_freq: Option<u32>,
     pub hclk_freq: Option<u32>,
     pub apb1_freq: Option<u32>,
+    pub pll_freq: Option<u32>,
+    pub pll_mul: Option<PLLMul>,
+    pub prediv: Option<PREDIV>,
+    pub ahb_divider: Option<AHBDivider>,
+    pub apb1_divider: Option<APB1Divider>,
+    pub flash_latency: Option<FlashLatency>,
+    pub hsi_calibration: Option<u32>,
+    pub css_enabled: bool,
+    pub lse: Option<LSE>,
+    pub lsi_freq: u32,
+    pub rtc_clock_source: Option<RTCClockSource>,
+    /// None if USB isn't used
+    pub usb_clock_source: Option<USBClockSource>,
+    pub crs: Option<CRS>,
+    pub mco: Option<MCO>,
+    pub kernel_clocks: Vec<KernelClock>,
+}
+
+impl RCC {
+    pub fn kernel_clock_source(&self, switch: &KernelClockSwitch) -> KernelClockSource {
+        self.kernel_clocks
+            .iter()
+            .find(|kernel_clock| std::ptr::eq(kernel_clock.switch, switch))
+            .map_or(switch.reset_source(), |kernel_clock| kernel_clock.source)
+    }
+
+    /// LSI only has to be started for the RTC, the IWDG starts it by itself
+    pub fn lsi_enabled(&self) -> bool {
+        self.rtc_clock_source == Some(RTCClockSource::RCC_RTCCLKSOURCE_LSI)
+    }
+
+    /// HSI48 has to be started if it clocks USB or the PLL but isn't the system clock
+    pub fn hsi48_needed(&self) -> bool {
+        let pll_hsi48 = self.pll_clock_source == Some(PLLSourceType::RCC_PLLSOURCE_HSI48);
+        self.clock_source != ClockSource::HSI48
+            && (self.usb_clock_source == Some(USBClockSource::RCC_USBCLKSOURCE_HSI48)
+                || (pll_hsi48
+                    && self.usb_clock_source == Some(USBClockSource::RCC_USBCLKSOURCE_PLL)))
+    }
+
+    /// Whether the USB clock comes from a crystal or is synchronized by the CRS
+    pub fn is_usb_clock_accurate(&self) -> bool {
+        // any synchronization source is good, they are all derived from crystals
+        let crs_synced = self.crs.is_some();
+
+        match (self.usb_clock_source, self.pll_clock_source) {
+            (Some(USBClockSource::RCC_USBCLKSOURCE_HSI48), _) => crs_synced,
+            (
+                Some(USBClockSource::RCC_USBCLKSOURCE_PLL),
+                Some(PLLSourceType::RCC_PLLSOURCE_HSE),
+            ) => true,
+            (
+                Some(USBClockSource::RCC_USBCLKSOURCE_PLL),
+                Some(PLLSourceType::RCC_PLLSOURCE_HSI48),
+            ) => crs_synced,
+            (Some(USBClockSource::RCC_USBCLKSOURCE_PLL), _) => false,
+            (None, _) => true,
+        }
+    }
+}
+
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum KernelClockSource {
+    PCLK1,
+    SYSCLK,
+    LSE,
+    HSI,
+}
+
+/// A clock switch in RCC_CFGR3 that selects the kernel clock of a peripheral
+#[derive(Debug)]
+pub struct KernelClockSwitch {
+    pub peripheral: &'static str,
+    pub ioc_key: &'static str,
+    /// ioc key of the resulting frequency
+    pub freq_key: &'static str,
+    /// the switch only exists on some parts, only used if it's set in the ioc file
+    pub optional: bool,
+    /// RCC_CFGR3 field
+    pub field: &'static str,
+    /// ioc name, source and field value, the first one is the reset selection
+    pub sources: &'static [(&'static str, KernelClockSource, u8)],
+}
+
+impl KernelClockSwitch {
+    pub fn reset_source(&self) -> KernelClockSource {
+        self.sources[0].1
+    }
+
+    pub fn bits(&self, source: KernelClockSource) -> u8 {
+        self.sources
+            .iter()
+            .find(|&&(_, other, _)| other == source)
+            .map(|&(_, _, bits)| bits)
+            .unwrap()
+    }
+
+    pub fn is_single_bit(&self) -> bool {
+        self.sources.len() == 2
+    }
+}
+
+const USART_SOURCES: &[(&str, KernelClockSource, u8)] = &[
+    ("PCLK1", KernelClockSource::PCLK1, 0b00),
+    ("SYSCLK", KernelClockSource::SYSCLK, 0b01),
+    ("LSE", KernelClockSource::LSE, 0b10),
+    ("HSI", KernelClockSource::HSI, 0b11),
+];
+
+// sic, CubeMX spells the keys this way
+pub static KERNEL_CLOCK_SWITCHES: [KernelClockSwitch; 5] = [
+    KernelClockSwitch {
+        peripheral: "USART1",
+        ioc_key: "USART1CLockSelection",
+        freq_key: "USART1Freq_Value",
+        optional: false,
+        field: "usart1sw",
+        sources: USART_SOURCES,
+    },
+    KernelClockSwitch {
+        peripheral: "USART2",
+        ioc_key: "USART2CLockSelection",
+        freq_key: "USART2Freq_Value",
+        optional: true,
+        field: "usart2sw",
+        sources: USART_SOURCES,
+    },
+    KernelClockSwitch {
+        peripheral: "USART3",
+        ioc_key: "USART3CLockSelection",
+        freq_key: "USART3Freq_Value",
+        optional: true,
+        field: "usart3sw",
+        sources: USART_SOURCES,
+    },
+    KernelClockSwitch {
+        peripheral: "I2C1",
+        ioc_key: "I2c1ClockSelection",
+        freq_key: "I2C1Freq_Value",
+        optional: false,
+        field: "i2c1sw",
+        sources: &[
+            ("HSI", KernelClockSource::HSI, 0),
+            ("SYSCLK", KernelClockSource::SYSCLK, 1),
+        ],
+    },
+    // HSI is divided by 244 for CEC
+    KernelClockSwitch {
+        peripheral: "CEC",
+        ioc_key: "CecClockSelection",
+        freq_key: "CECFreq_Value",
+        optional: false,
+        field: "cecsw",
+        sources: &[
+            ("HSI", KernelClockSource::HSI, 0),
+            ("LSE", KernelClockSource::LSE, 1),
+        ],
+    },
+];
+
+#[derive(Debug)]
+pub struct KernelClock {
+    pub switch: &'static KernelClockSwitch,
+    pub source: KernelClockSource,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct MCO {
+    pub source: MCOSource,
+    pub divider: MCODivider,
 }
 
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct CRS {
+    pub sync_source: CRSSyncSource,
+    pub polarity: CRSPolarity,
+    pub prescaler: CRSPrescaler,
+    pub reload: u32,
+    pub error_limit: u32,
+    pub hsi48_calibration: u32,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub struct LSE {
+    pub bypass: bool,
+    pub drive: LSEDrive,
+    pub freq: u32,
+}
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, PartialEq)]
 pub enum ClockSource {
     HSI,
@@ -99,3 +625,268 @@ parameter!(
     PLLSourceType,
     [RCC_PLLSOURCE_HSI, RCC_PLLSOURCE_HSI48, RCC_PLLSOURCE_HSE]
 );
+
+parameter!(
+    PLLMul,
+    [
+        RCC_PLL_MUL2,
+        RCC_PLL_MUL3,
+        RCC_PLL_MUL4,
+        RCC_PLL_MUL5,
+        RCC_PLL_MUL6,
+        RCC_PLL_MUL7,
+        RCC_PLL_MUL8,
+        RCC_PLL_MUL9,
+        RCC_PLL_MUL10,
+        RCC_PLL_MUL11,
+        RCC_PLL_MUL12,
+        RCC_PLL_MUL13,
+        RCC_PLL_MUL14,
+        RCC_PLL_MUL15,
+        RCC_PLL_MUL16
+    ]
+);
+
+impl PLLMul {
+    pub fn factor(self) -> u32 {
+        // variants are declared in order starting at 2
+        self as u32 + 2
+    }
+}
+
+parameter!(
+    LSEDrive,
+    [
+        RCC_LSEDRIVE_LOW,
+        RCC_LSEDRIVE_MEDIUMLOW,
+        RCC_LSEDRIVE_MEDIUMHIGH,
+        RCC_LSEDRIVE_HIGH
+    ],
+    default = RCC_LSEDRIVE_LOW
+);
+
+impl LSEDrive {
+    /// value of RCC_BDCR.LSEDRV
+    pub fn bits(self) -> u8 {
+        match self {
+            LSEDrive::RCC_LSEDRIVE_LOW => 0b00,
+            LSEDrive::RCC_LSEDRIVE_MEDIUMLOW => 0b10,
+            LSEDrive::RCC_LSEDRIVE_MEDIUMHIGH => 0b01,
+            LSEDrive::RCC_LSEDRIVE_HIGH => 0b11,
+        }
+    }
+}
+
+parameter!(
+    RTCClockSource,
+    [
+        RCC_RTCCLKSOURCE_LSE,
+        RCC_RTCCLKSOURCE_LSI,
+        RCC_RTCCLKSOURCE_HSE_DIV32
+    ]
+);
+
+impl RTCClockSource {
+    /// value of RCC_BDCR.RTCSEL
+    pub fn bits(self) -> u8 {
+        match self {
+            RTCClockSource::RCC_RTCCLKSOURCE_LSE => 0b01,
+            RTCClockSource::RCC_RTCCLKSOURCE_LSI => 0b10,
+            RTCClockSource::RCC_RTCCLKSOURCE_HSE_DIV32 => 0b11,
+        }
+    }
+}
+
+parameter!(
+    USBClockSource,
+    [RCC_USBCLKSOURCE_HSI48, RCC_USBCLKSOURCE_PLL],
+    default = RCC_USBCLKSOURCE_HSI48
+);
+
+parameter!(
+    CRSSyncSource,
+    [
+        RCC_CRS_SYNC_SOURCE_GPIO,
+        RCC_CRS_SYNC_SOURCE_LSE,
+        RCC_CRS_SYNC_SOURCE_USB
+    ]
+);
+
+impl CRSSyncSource {
+    /// value of CRS_CFGR.SYNCSRC
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+parameter!(
+    CRSPolarity,
+    [RCC_CRS_SYNC_POLARITY_RISING, RCC_CRS_SYNC_POLARITY_FALLING],
+    default = RCC_CRS_SYNC_POLARITY_RISING
+);
+
+parameter!(
+    CRSPrescaler,
+    [
+        RCC_CRS_SYNC_DIV1,
+        RCC_CRS_SYNC_DIV2,
+        RCC_CRS_SYNC_DIV4,
+        RCC_CRS_SYNC_DIV8,
+        RCC_CRS_SYNC_DIV16,
+        RCC_CRS_SYNC_DIV32,
+        RCC_CRS_SYNC_DIV64,
+        RCC_CRS_SYNC_DIV128
+    ],
+    default = RCC_CRS_SYNC_DIV1
+);
+
+impl CRSPrescaler {
+    /// value of CRS_CFGR.SYNCDIV
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+parameter!(
+    MCOSource,
+    [
+        RCC_MCO1SOURCE_NOCLOCK,
+        RCC_MCO1SOURCE_HSI14,
+        RCC_MCO1SOURCE_LSI,
+        RCC_MCO1SOURCE_LSE,
+        RCC_MCO1SOURCE_SYSCLK,
+        RCC_MCO1SOURCE_HSI,
+        RCC_MCO1SOURCE_HSE,
+        RCC_MCO1SOURCE_PLLCLK_DIV2,
+        RCC_MCO1SOURCE_HSI48,
+        RCC_MCO1SOURCE_PLLCLK
+    ],
+    default = RCC_MCO1SOURCE_SYSCLK
+);
+
+impl MCOSource {
+    /// value of RCC_CFGR.MCO
+    pub fn bits(self) -> u8 {
+        match self {
+            MCOSource::RCC_MCO1SOURCE_PLLCLK => 0b0111,
+            source => source as u8,
+        }
+    }
+}
+
+parameter!(
+    MCODivider,
+    [
+        RCC_MCODIV_1,
+        RCC_MCODIV_2,
+        RCC_MCODIV_4,
+        RCC_MCODIV_8,
+        RCC_MCODIV_16,
+        RCC_MCODIV_32,
+        RCC_MCODIV_64,
+        RCC_MCODIV_128
+    ],
+    default = RCC_MCODIV_1
+);
+
+impl MCODivider {
+    /// value of RCC_CFGR.MCOPRE
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+
+    pub fn divider(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+parameter!(
+    PREDIV,
+    [
+        RCC_PREDIV_DIV1,
+        RCC_PREDIV_DIV2,
+        RCC_PREDIV_DIV3,
+        RCC_PREDIV_DIV4,
+        RCC_PREDIV_DIV5,
+        RCC_PREDIV_DIV6,
+        RCC_PREDIV_DIV7,
+        RCC_PREDIV_DIV8,
+        RCC_PREDIV_DIV9,
+        RCC_PREDIV_DIV10,
+        RCC_PREDIV_DIV11,
+        RCC_PREDIV_DIV12,
+        RCC_PREDIV_DIV13,
+        RCC_PREDIV_DIV14,
+        RCC_PREDIV_DIV15,
+        RCC_PREDIV_DIV16
+    ],
+    default = RCC_PREDIV_DIV1
+);
+
+impl PREDIV {
+    pub fn divider(self) -> u32 {
+        // variants are declared in order starting at 1
+        self as u32 + 1
+    }
+}
+
+parameter!(
+    AHBDivider,
+    [
+        RCC_SYSCLK_DIV1,
+        RCC_SYSCLK_DIV2,
+        RCC_SYSCLK_DIV4,
+        RCC_SYSCLK_DIV8,
+        RCC_SYSCLK_DIV16,
+        RCC_SYSCLK_DIV64,
+        RCC_SYSCLK_DIV128,
+        RCC_SYSCLK_DIV256,
+        RCC_SYSCLK_DIV512
+    ],
+    default = RCC_SYSCLK_DIV1
+);
+
+impl AHBDivider {
+    pub fn divider(self) -> u32 {
+        match self {
+            AHBDivider::RCC_SYSCLK_DIV1 => 1,
+            AHBDivider::RCC_SYSCLK_DIV2 => 2,
+            AHBDivider::RCC_SYSCLK_DIV4 => 4,
+            AHBDivider::RCC_SYSCLK_DIV8 => 8,
+            AHBDivider::RCC_SYSCLK_DIV16 => 16,
+            AHBDivider::RCC_SYSCLK_DIV64 => 64,
+            AHBDivider::RCC_SYSCLK_DIV128 => 128,
+            AHBDivider::RCC_SYSCLK_DIV256 => 256,
+            AHBDivider::RCC_SYSCLK_DIV512 => 512,
+        }
+    }
+}
+
+parameter!(
+    APB1Divider,
+    [
+        RCC_HCLK_DIV1,
+        RCC_HCLK_DIV2,
+        RCC_HCLK_DIV4,
+        RCC_HCLK_DIV8,
+        RCC_HCLK_DIV16
+    ],
+    default = RCC_HCLK_DIV1
+);
+
+impl APB1Divider {
+    pub fn divider(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+parameter!(
+    FlashLatency,
+    [FLASH_LATENCY_0, FLASH_LATENCY_1, FLASH_LATENCY_2]
+);
+
+impl FlashLatency {
+    pub fn wait_states(self) -> usize {
+        self as usize
+    }
+}
@@ -0,0 +1,197 @@
+use crate::clocks::Clock;
+use crate::*;
+
+/// size of a clock box in the SVG
+const NODE_WIDTH: u32 = 150;
+const NODE_HEIGHT: u32 = 40;
+/// room between the columns for the setting labels
+const COLUMN_GAP: u32 = 110;
+const ROW_GAP: u32 = 16;
+const MARGIN: u32 = 20;
+
+/// Human readable frequency, e.g. 48 MHz or 32.768 kHz
+pub fn format_freq(freq: u32) -> String {
+    if freq >= 1_000_000 {
+        f!("{} MHz", freq as f64 / 1e6)
+    } else if freq >= 1_000 {
+        f!("{} kHz", freq as f64 / 1e3)
+    } else {
+        f!("{freq} Hz")
+    }
+}
+
+/// The clock tree as a text table with one row per clock
+pub fn generate_clock_table(config: &Config) -> String {
+    let rows: Vec<[String; 4]> = config
+        .clocks
+        .clocks
+        .iter()
+        .map(|clock| {
+            [
+                clock.name.to_string(),
+                clock.source.unwrap_or("-").to_string(),
+                clock.setting.clone().unwrap_or_else(|| "-".to_string()),
+                format_freq(clock.freq),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "Clock".to_string(),
+        "Source".to_string(),
+        "Setting".to_string(),
+        "Frequency".to_string(),
+    ];
+
+    let mut widths = [0; 4];
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let format_row = |row: &[String; 4]| -> String {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, &width)| f!("{:width$}", cell, width = width))
+            .collect();
+        cells.join(" | ").trim_end().to_string()
+    };
+
+    let mut string = GeneratedString::new();
+    string.line(f!("Clock tree of {config.mcu_name}"));
+    string.empty_line();
+    string.line(format_row(&header));
+    let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    string.line(separator.join("-|-"));
+    for row in rows.iter() {
+        string.line(format_row(row));
+    }
+
+    string.string
+}
+
+/// The clock tree as a Graphviz DOT graph
+pub fn generate_clock_dot(config: &Config) -> String {
+    let mut string = GeneratedString::new();
+
+    string.line("digraph clock_tree {");
+    string.indent_right();
+    string.line("rankdir=LR;");
+    string.line("node [shape=box];");
+    string.empty_line();
+
+    for clock in config.clocks.clocks.iter() {
+        let freq = format_freq(clock.freq);
+        string.line(f!("\"{clock.name}\" [label=\"{clock.name}\\n{freq}\"];"));
+    }
+    string.empty_line();
+
+    for clock in config.clocks.clocks.iter() {
+        let source = match clock.source {
+            Some(source) => source,
+            None => continue,
+        };
+        match &clock.setting {
+            Some(setting) => string.line(f!(
+                "\"{source}\" -> \"{clock.name}\" [label=\"{setting}\"];"
+            )),
+            None => string.line(f!("\"{source}\" -> \"{clock.name}\";")),
+        }
+    }
+
+    string.indent_left();
+    string.line("}");
+
+    string.string
+}
+
+/// The clock tree as a self-contained SVG, one column per derivation step
+pub fn generate_clock_svg(config: &Config) -> String {
+    let clocks = &config.clocks.clocks;
+
+    // sources are always added before the clocks derived from them
+    let mut positions: HashMap<&str, (u32, u32)> = HashMap::new();
+    let mut rows_per_column: Vec<u32> = Vec::new();
+    for clock in clocks.iter() {
+        let column = clock
+            .source
+            .and_then(|source| positions.get(source))
+            .map_or(0, |&(column, _)| column + 1);
+        if rows_per_column.len() <= column as usize {
+            rows_per_column.push(0);
+        }
+        let row = rows_per_column[column as usize];
+        rows_per_column[column as usize] += 1;
+        positions.insert(clock.name, (column, row));
+    }
+
+    let position = |clock: &Clock| -> (u32, u32) {
+        let (column, row) = positions[clock.name];
+        (
+            MARGIN + column * (NODE_WIDTH + COLUMN_GAP),
+            MARGIN + row * (NODE_HEIGHT + ROW_GAP),
+        )
+    };
+
+    let columns = rows_per_column.len() as u32;
+    let rows = rows_per_column.iter().copied().max().unwrap_or_default();
+    let width = 2 * MARGIN + columns * NODE_WIDTH + columns.saturating_sub(1) * COLUMN_GAP;
+    let height = 2 * MARGIN + rows * NODE_HEIGHT + rows.saturating_sub(1) * ROW_GAP;
+
+    let mut string = GeneratedString::new();
+    string.line(f!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" font-family=\"monospace\" font-size=\"12\">"
+    ));
+    string.indent_right();
+    string.line(f!("<title>Clock tree of {config.mcu_name}</title>"));
+    string.line("<rect width=\"100%\" height=\"100%\" fill=\"white\"/>");
+
+    // edges first, so the boxes are drawn on top
+    for clock in clocks.iter() {
+        let source = match clock
+            .source
+            .and_then(|source| clocks.iter().find(|other| other.name == source))
+        {
+            Some(source) => source,
+            None => continue,
+        };
+
+        let (source_x, source_y) = position(source);
+        let (x, y) = position(clock);
+        let (x1, y1) = (source_x + NODE_WIDTH, source_y + NODE_HEIGHT / 2);
+        let (x2, y2) = (x, y + NODE_HEIGHT / 2);
+        let middle = (x1 + x2) / 2;
+        string.line(f!(
+            "<path d=\"M {x1} {y1} C {middle} {y1}, {middle} {y2}, {x2} {y2}\" fill=\"none\" stroke=\"black\"/>"
+        ));
+
+        if let Some(setting) = &clock.setting {
+            let (label_x, label_y) = (x2 - 4, y2 - 4);
+            string.line(f!(
+                "<text x=\"{label_x}\" y=\"{label_y}\" text-anchor=\"end\" font-size=\"10\">{setting}</text>"
+            ));
+        }
+    }
+
+    for clock in clocks.iter() {
+        let (x, y) = position(clock);
+        let (text_x, name_y, freq_y) = (x + NODE_WIDTH / 2, y + 16, y + 32);
+        let freq = format_freq(clock.freq);
+        string.line(f!(
+            "<rect x=\"{x}\" y=\"{y}\" width=\"{NODE_WIDTH}\" height=\"{NODE_HEIGHT}\" fill=\"#e8f0fe\" stroke=\"black\"/>"
+        ));
+        string.line(f!(
+            "<text x=\"{text_x}\" y=\"{name_y}\" text-anchor=\"middle\" font-weight=\"bold\">{clock.name}</text>"
+        ));
+        string.line(f!(
+            "<text x=\"{text_x}\" y=\"{freq_y}\" text-anchor=\"middle\">{freq}</text>"
+        ));
+    }
+
+    string.indent_left();
+    string.line("</svg>");
+
+    string.string
+}
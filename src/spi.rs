@@ -3,6 +3,7 @@ use std::convert::TryFrom;
 
 const MAX_SPIS: u8 = 6;
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
 pub struct SPI {
     pub name_lower: String,
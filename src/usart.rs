@@ -2,6 +2,7 @@ use crate::*;
 
 const MAX_USARTS: u8 = 8;
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
 pub struct USART {
     pub name_lower: String,
@@ -0,0 +1,143 @@
+use std::convert::TryFrom;
+
+use regex::Regex;
+
+use crate::*;
+
+/// A constant defined in CubeMX, e.g. BUFFER_SIZE=64
+#[derive(Debug)]
+pub struct UserConstant {
+    pub name: String,
+    pub value: ConstantValue,
+}
+
+/// The inferred type with the value as rust literal
+#[derive(Debug, PartialEq)]
+pub enum ConstantValue {
+    U32(String),
+    I32(String),
+    U64(String),
+    I64(String),
+    F32(String),
+    Str(String),
+}
+
+impl ConstantValue {
+    pub fn rust_type(&self) -> &'static str {
+        match self {
+            ConstantValue::U32(_) => "u32",
+            ConstantValue::I32(_) => "i32",
+            ConstantValue::U64(_) => "u64",
+            ConstantValue::I64(_) => "i64",
+            ConstantValue::F32(_) => "f32",
+            ConstantValue::Str(_) => "&str",
+        }
+    }
+
+    pub fn literal(&self) -> &str {
+        match self {
+            ConstantValue::U32(literal)
+            | ConstantValue::I32(literal)
+            | ConstantValue::U64(literal)
+            | ConstantValue::I64(literal)
+            | ConstantValue::F32(literal)
+            | ConstantValue::Str(literal) => literal,
+        }
+    }
+}
+
+pub fn get_user_constants(config: &ConfigParams<'_>) -> anyhow::Result<Vec<UserConstant>> {
+    let mut user_constants: Vec<UserConstant> = Vec::new();
+
+    let constants = match config.get("Mcu").and_then(|mcu| mcu.get("UserConstants")) {
+        Some(constants) => constants,
+        None => return Ok(user_constants),
+    };
+
+    let identifier = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+
+    // e.g. Mcu.UserConstants=BUFFER_SIZE,64;TIMEOUT,1.5;NAME,"board"
+    for constant in constants.split(';').filter(|constant| !constant.is_empty()) {
+        let (name, value) = match constant.split_once(',') {
+            Some((name, value)) => (name.trim(), value.trim()),
+            None => bail!("Couldn't parse user constant {}", constant),
+        };
+
+        ensure!(
+            identifier.is_match(name),
+            "User constant {} isn't a valid identifier",
+            name
+        );
+        ensure!(
+            user_constants.iter().all(|other| other.name != name),
+            "User constant {} is defined more than once",
+            name
+        );
+
+        let value = match parse_value(value) {
+            Some(value) => value,
+            None => {
+                // e.g. expressions like BUFFER_SIZE*2
+                print_warning(f!(
+                    "User constant {name}={value} has no integer, float or string value, it's skipped"
+                ));
+                continue;
+            }
+        };
+
+        user_constants.push(UserConstant {
+            name: name.to_string(),
+            value,
+        });
+    }
+
+    Ok(user_constants)
+}
+
+fn parse_value(value: &str) -> Option<ConstantValue> {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        return Some(ConstantValue::Str(value.to_string()));
+    }
+
+    // C suffixes like 100U or 100UL
+    let number = value.trim_end_matches(&['u', 'U', 'l', 'L'][..]);
+
+    let hex = number
+        .strip_prefix("0x")
+        .or_else(|| number.strip_prefix("0X"));
+    let integer = match hex {
+        Some(hex) => i128::from_str_radix(hex, 16).ok(),
+        None => number.parse::<i128>().ok(),
+    };
+
+    if let Some(integer) = integer {
+        let literal = match hex {
+            Some(_) => f!("{integer:#x}"),
+            None => integer.to_string(),
+        };
+
+        return if u32::try_from(integer).is_ok() {
+            Some(ConstantValue::U32(literal))
+        } else if i32::try_from(integer).is_ok() {
+            Some(ConstantValue::I32(literal))
+        } else if u64::try_from(integer).is_ok() {
+            Some(ConstantValue::U64(literal))
+        } else if i64::try_from(integer).is_ok() {
+            Some(ConstantValue::I64(literal))
+        } else {
+            None
+        };
+    }
+
+    // 1.5, 2e3 or 1.5f
+    let float = value.trim_end_matches(&['f', 'F'][..]);
+    if float.contains(|c: char| c.is_ascii_digit()) {
+        if let Ok(float) = float.parse::<f32>() {
+            if float.is_finite() {
+                return Some(ConstantValue::F32(f!("{float:?}")));
+            }
+        }
+    }
+
+    None
+}
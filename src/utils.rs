@@ -29,17 +29,6 @@ where
     T::try_from(param)
 }
 
-pub fn parse_mandatory_u32(
-    parameters: &HashMap<&str, &str>,
-    param_name: &str,
-) -> anyhow::Result<u32> {
-    Ok(parameters
-        .get(param_name)
-        .ok_or_else(|| anyhow!(f!("{param_name} parameter not found")))?
-        .parse()
-        .map_err(|_| anyhow!(f!("{param_name} parameter invalid integer")))?)
-}
-
 pub fn parse_optional_u32(
     parameters: &HashMap<&str, &str>,
     param_name: &str,
@@ -53,11 +42,15 @@ pub fn parse_optional_u32(
         .transpose()
 }
 
+pub fn print_warning<T: AsRef<str>>(warning: T) {
+    println!("Warning: {}", warning.as_ref());
+}
+
 // Generate an enum with an TryFrom<&str> implementation that converts from a string to a enum variant
 // enum will also derive Debug, Copy, Clone, PartialEq
 macro_rules! parameter {
     ($enumname:ident, [$($variant: ident), *]) => {
-        #[allow(non_camel_case_types)]
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
         #[derive(Debug, Copy, Clone, PartialEq)]
         pub enum $enumname {
             $(
@@ -116,7 +109,7 @@ impl GeneratedString {
     }
 
     pub fn empty_line(&mut self) {
-        self.string.push_str("\n");
+        self.string.push('\n');
     }
 
     pub fn indent_right(&mut self) {
@@ -127,3 +120,61 @@ impl GeneratedString {
         self.indent = self.indent.saturating_sub(4);
     }
 }
+
+/// Rust keywords, including the reserved ones, they can't be used as variable names
+const KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "union", "unsafe", "unsized", "use", "virtual", "where", "while",
+];
+
+/// Turns a CubeMX label into a snake_case identifier, e.g. "LED Red" into led_red
+///
+/// Returns None if nothing usable is left, e.g. for "!!!"
+pub fn to_identifier(label: &str) -> Option<String> {
+    let mut identifier = String::new();
+    let mut previous: Option<char> = None;
+
+    for c in label.chars() {
+        if c.is_ascii_alphanumeric() {
+            // LedRed becomes led_red
+            if c.is_ascii_uppercase()
+                && previous.map_or(false, |previous| previous.is_ascii_lowercase())
+            {
+                identifier.push('_');
+            }
+            identifier.push(c.to_ascii_lowercase());
+        } else if !identifier.is_empty() && !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+        previous = Some(c);
+    }
+
+    let identifier = identifier.trim_end_matches('_');
+    if identifier.is_empty() {
+        return None;
+    }
+
+    Some(if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        f!("pin_{identifier}")
+    } else if KEYWORDS.contains(&identifier) {
+        f!("{identifier}_")
+    } else {
+        identifier.to_string()
+    })
+}
+
+/// e.g. out_1 to Out1, spi1_sck to Spi1Sck
+pub fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use std::fs;
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
 
 const IOC_FILE: &str = "tests/stm32f042.ioc";
@@ -44,6 +45,67 @@ fn test_config() {
     assert!(config.is_ok());
 }
 
+/// Pins that don't exist on the package or are assigned twice are rejected
+#[test]
+fn test_pin_validation() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PE5.Signal=GPIO_Output\nPB6.Signal=USART1_TX\n";
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("PE5 doesn't exist on package LQFP48"));
+    assert!(message.contains("USART1_TX is assigned to more than one pin: PA9, PB6"));
+}
+
+/// DMA requests have to be on a channel that can serve them
+#[test]
+fn test_dma_validation() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "Dma.Request0=USART1_TX\nDma.Request1=SPI1_TX\nDma.RequestsNb=2\n\
+           Dma.USART1_TX.0.Instance=DMA1_Channel3\nDma.SPI1_TX.1.Instance=DMA1_Channel3\n";
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("DMA1_Channel3 is used by both USART1_TX and SPI1_TX"));
+    assert!(message.contains(
+        "USART1_TX can't use DMA1_Channel3, valid channels: DMA1_Channel2, DMA1_Channel4"
+    ));
+
+    // the F042 has no second I2C
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "Dma.Request0=I2C2_TX\nDma.RequestsNb=1\nDma.I2C2_TX.0.Instance=DMA1_Channel4\n";
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    assert!(format!("{:?}", error).contains("STM32F042C6Tx has no DMA request I2C2_TX"));
+}
+
+/// The exact MCU is resolved from ordering codes and CubeMX range notation
+#[test]
+fn test_mcu_resolution() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed");
+
+    let ordering_code =
+        file_content.replace("Mcu.UserName=STM32F042C6Tx", "Mcu.UserName=STM32F042C6T6");
+    let config = cube2rust::load_ioc(&ordering_code).expect("load failed");
+    assert_eq!(config.mcu_name, "STM32F042C6Tx");
+
+    // tape and reel packing
+    let packing =
+        file_content.replace("Mcu.UserName=STM32F042C6Tx", "Mcu.UserName=STM32F042C6T6TR");
+    let config = cube2rust::load_ioc(&packing).expect("load failed");
+    assert_eq!(config.mcu_name, "STM32F042C6Tx");
+
+    let range_only = file_content.replace("Mcu.UserName=STM32F042C6Tx", "");
+    let error = cube2rust::load_ioc(&range_only).unwrap_err();
+    assert!(format!("{:?}", error).contains("STM32F042C4Tx, STM32F042C6Tx"));
+
+    let unknown = file_content.replace("Mcu.UserName=STM32F042C6Tx", "Mcu.UserName=STM32F043C6Tx");
+    let error = cube2rust::load_ioc(&unknown).unwrap_err();
+    assert!(format!("{:?}", error).contains("STM32F042C6Tx"));
+}
+
 /// Load in ioc file and print it
 #[test]
 fn test_params() {
@@ -53,3 +115,678 @@ fn test_params() {
 
     dbg!(config_params);
 }
+
+/// Runs cube2rust on the given ioc content in a fresh temporary project
+/// and returns the project directory
+fn generate_project(name: &str, file_content: &str) -> PathBuf {
+    let project_dir = std::env::temp_dir().join(name);
+    let _ = fs::remove_dir_all(&project_dir);
+    fs::create_dir_all(&project_dir).expect("Failed to create project directory");
+
+    let config = cube2rust::load_ioc(file_content).expect("load failed");
+    cube2rust::generate(&project_dir, config).expect("generate failed");
+
+    project_dir
+}
+
+/// Generates src/main.rs for the given ioc content without creating a project
+fn generate_main(file_content: &str) -> String {
+    let config = cube2rust::load_ioc(file_content).expect("load failed");
+    cube2rust::generate_main(&config).expect("generate failed")
+}
+
+/// Pins of peripherals without a driver yet still get their alternate function
+#[test]
+fn test_alternate_functions() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PB4.Signal=TIM3_CH1\nPA11.Signal=CAN_RX\nPA4.Signal=SPI1_NSS\n";
+
+    let config = cube2rust::load_ioc(&file_content).expect("load failed");
+    let get_af = |register: &str| {
+        let gpio = config.gpios.iter().find(|gpio| gpio.register == register);
+        gpio.unwrap()
+            .get_alternate_function(&config)
+            .expect("no alternate function")
+    };
+
+    assert_eq!(get_af("pb4"), Some(1));
+    assert_eq!(get_af("pa11"), Some(4));
+    assert_eq!(get_af("pa4"), Some(0));
+    assert_eq!(get_af("pa9"), Some(1));
+    assert_eq!(get_af("pb13"), None);
+}
+
+/// A generated project has its target, main.rs and memory.x,
+/// memory.x uses the real flash origin and names every region
+#[test]
+fn test_generate_project() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed");
+
+    let project_dir = generate_project("cube2rust_test_generate_project", &file_content);
+    let cargo_config = fs::read_to_string(project_dir.join(".cargo/config")).expect("read failed");
+    let main_rs = fs::read_to_string(project_dir.join("src/main.rs")).expect("read failed");
+    let memory_x = fs::read_to_string(project_dir.join("memory.x")).expect("read failed");
+
+    assert!(cargo_config.contains("target = \"thumbv6m-none-eabi\""));
+    assert!(main_rs.contains("#[entry]"));
+    assert!(memory_x.contains("FLASH : ORIGIN = 0x08000000, LENGTH = 32K"));
+    assert!(memory_x.contains("RAM : ORIGIN = 0x20000000, LENGTH = 6K"));
+    assert!(memory_x.contains("OPTION_BYTES : ORIGIN = 0x1FFFF800, LENGTH = 16"));
+}
+
+/// Enabled interrupts get their PAC name, vectors the MCU doesn't have are rejected
+#[test]
+fn test_interrupts() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "NVIC.USART1_IRQn=true\\:1\\:0\\:false\\:false\\:true\\:true\\:true\n\
+           NVIC.DMA1_Channel4_5_IRQn=true\\:0\\:0\\:false\\:false\\:true\\:true\\:true\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("cp.NVIC.set_priority(interrupt::USART1, 1 << 6);"));
+    assert!(main_rs.contains("stm32::NVIC::unmask(interrupt::DMA1_CH4_5_6_7);"));
+    assert!(main_rs.contains("#[interrupt]\nfn USART1() {}"));
+
+    let file_content =
+        file_content + "NVIC.USART3_4_IRQn=true\\:0\\:0\\:false\\:false\\:true\\:true\\:true\n";
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("STM32F042C6Tx has no interrupt vector USART3_4_IRQn"));
+}
+
+/// Out of spec clock trees are reported with the ioc key
+#[test]
+fn test_rcc_validation() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "RCC.SYSCLKFreq_VALUE=72000000\nRCC.PLLCLKFreq_Value=72000000\n\
+           RCC.PLLMUL=RCC_PLL_MUL9\nRCC.HSE_VALUE=40000000\n";
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("RCC.SYSCLKFreq_VALUE=72000000 exceeds the maximum of 48000000 Hz"));
+    assert!(message.contains("RCC.HCLKFreq_Value=48000000 can't be reached"));
+    assert!(message.contains("RCC.HSE_VALUE=40000000 is outside the HSE range"));
+    assert!(message.contains("RCC.PLLCLKFreq_Value=72000000 is outside the PLL output range"));
+
+    // the AHB prescaler has no /32
+    let file_content = fs::read_to_string(IOC_FILE)
+        .expect("read failed")
+        .replace("RCC.HCLKFreq_Value=48000000", "RCC.HCLKFreq_Value=1500000");
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    assert!(format!("{:?}", error)
+        .contains("RCC.HCLKFreq_Value=1500000 can't be reached from SYSCLK 48000000 Hz"));
+}
+
+/// The computed clock tree has to match CubeMX and ends up in a clocks module
+#[test]
+fn test_clock_tree() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "RCC.EnbaleCSS=ENABLE\nRCC.HSICalibrationValue=20\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("pub const SYSCLK_HZ: u32 = 48000000;"));
+    assert!(main_rs.contains("pub const PLL_INPUT_HZ: u32 = 8000000;"));
+    assert!(main_rs.contains("pub const CEC_HZ: u32 = 32786;"));
+    assert!(main_rs.contains(".cr.modify(|_, w| w.hsitrim().bits(20).csson().set_bit());"));
+
+    let file_content = file_content + "RCC.PREDIV=RCC_PREDIV_DIV2\n";
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(
+        message.contains("RCC.PLLCLKFreq_Value=48000000 but the computed PLLCLK is 24000000 Hz")
+    );
+}
+
+/// The LSE pins aren't GPIOs, LSE and the RTC clock are set up in the backup domain
+#[test]
+fn test_low_speed_clocks() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PC14-OSC32_IN.Mode=LSE-External-Oscillator\nPC14-OSC32_IN.Signal=RCC_OSC32_IN\n\
+           PC15OSC32_OUT.Signal=RCC_OSC32_OUT\nRCC.LSE_Drive_Capability=RCC_LSEDRIVE_HIGH\n\
+           RCC.RTCClockSelection=RCC_RTCCLKSOURCE_LSE\nRCC.RTCFreq_Value=32768\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(!main_rs.contains("pc14"));
+    assert!(!main_rs.contains("pc15"));
+    assert!(
+        main_rs.contains("rcc_regs.bdcr.modify(|_, w| w.lsedrv().bits(0b11).lseon().set_bit());")
+    );
+    assert!(
+        main_rs.contains("rcc_regs.bdcr.modify(|_, w| w.rtcsel().bits(0b01).rtcen().set_bit());")
+    );
+    assert!(main_rs.contains("pub const RTC_HZ: u32 = 32768;"));
+
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "RCC.RTCClockSelection=RCC_RTCCLKSOURCE_LSE\n";
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("no pin has the RCC_OSC32_IN signal"));
+}
+
+/// Crystal-less USB runs from HSI48 synchronized to the USB SOF by the CRS
+#[test]
+fn test_usb_clock() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "USB.IPParameters=VirtualMode\nRCC.CRSActivatedSource=RCC_CRS_SYNC_SOURCE_USB\n\
+           RCC.USBFreq_Value=48000000\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("rcc_regs.cr2.modify(|_, w| w.hsi48on().set_bit());"));
+    assert!(main_rs.contains("rcc_regs.cfgr3.modify(|_, w| w.usbsw().clear_bit());"));
+    assert!(main_rs.contains("w.syncsrc().bits(0b10)"));
+    assert!(main_rs.contains(".reload().bits(47999)"));
+    assert!(main_rs.contains("pub const USB_HZ: u32 = 48000000;"));
+}
+
+/// The clock output on PA8 gets its alternate function and the source/prescaler setup
+#[test]
+fn test_mco() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PA8.Signal=RCC_MCO\nRCC.RCC_MCOSource=RCC_MCO1SOURCE_PLLCLK\n\
+           RCC.RCC_MCODiv=RCC_MCODIV_4\nRCC.MCOFreq_Value=12000000\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("pa8.into_alternate_af0(cs)"));
+    assert!(main_rs.contains(
+        "(*stm32::RCC::ptr()).cfgr.modify(|_, w| w.mco().bits(0b0111).mcopre().bits(0b010).pllnodiv().set_bit());"
+    ));
+    assert!(main_rs.contains("pub const MCO_HZ: u32 = 12000000;"));
+}
+
+/// Kernel clock switches end up in RCC_CFGR3 and the baud rate and I2C timing follow them
+#[test]
+fn test_kernel_clocks() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "RCC.APB1CLKDivider=RCC_HCLK_DIV2\nRCC.APB1Freq_Value=24000000\n\
+           RCC.USART1CLockSelection=RCC_USART1CLKSOURCE_SYSCLK\n\
+           RCC.I2c1ClockSelection=RCC_I2C1CLKSOURCE_SYSCLK\nRCC.I2C1Freq_Value=48000000\n";
+
+    let main_rs = generate_main(&file_content.replace("I2C1.Timing=0x00000001\n", ""));
+
+    assert!(main_rs.contains(".cfgr3.modify(|_, w| w.usart1sw().bits(0b01).i2c1sw().set_bit());"));
+    assert!(main_rs.contains("usart.brr.write(|w| w.bits(5000));"));
+    assert!(main_rs.contains("i2c.timingr.write(|w| w.bits(0x50100306));"));
+
+    // 36 MHz can't be divided down to 8 MHz, the timings are scaled to the 7.2 MHz prescaled clock
+    let file_content_36mhz = file_content
+        .replace("I2C1.Timing=0x00000001\n", "")
+        .replace("I2C_Fast_Plus", "I2C_Fast")
+        .replace("=48000000", "=36000000")
+        .replace("=24000000", "=18000000")
+        .replace("RCC_PLL_MUL6", "RCC_PLL_MUL9")
+        .replace(
+            "VCOOutput2Freq_Value=8000000",
+            "VCOOutput2Freq_Value=4000000",
+        )
+        + "RCC.PREDIV=RCC_PREDIV_DIV2\n";
+    let main_rs = generate_main(&file_content_36mhz);
+    assert!(main_rs.contains("i2c.timingr.write(|w| w.bits(0x40310308));"));
+
+    // CubeMX calculated the timing for the kernel clock already
+    let main_rs = generate_main(&file_content);
+    assert!(main_rs.contains("i2c.timingr.write(|w| w.bits(0x00000001));"));
+}
+
+/// The HAL's own timing is right for the 8 MHz HSI
+#[test]
+fn test_i2c_hsi_timing() {
+    let file_content = fs::read_to_string(IOC_FILE)
+        .expect("read failed")
+        .replace("I2C1.Timing=0x00000001\n", "");
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("let mut i2c1 = I2c::i2c1("));
+    assert!(!main_rs.contains("timingr"));
+}
+
+/// The oscillator pin is found by its signal, the frequency comes from HSE_VALUE
+#[test]
+fn test_hse_discovery() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "RCC.HSE_VALUE=16000000\nRCC.PREDIV=RCC_PREDIV_DIV2\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains(".hse(16000000.hz(), hal::rcc::HSEBypassMode::Bypassed)"));
+
+    let file_content = fs::read_to_string(IOC_FILE)
+        .expect("read failed")
+        .replace("PF0-OSC_IN.Signal=RCC_OSC_IN\n", "");
+
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("HSE is selected but no pin has the RCC_OSC_IN signal"));
+}
+
+/// The report renders every clock with its source, setting and frequency
+#[test]
+fn test_clock_report() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed");
+
+    let project_dir = std::env::temp_dir().join("cube2rust_test_clock_report");
+    let _ = fs::remove_dir_all(&project_dir);
+    fs::create_dir_all(&project_dir).expect("Failed to create project directory");
+
+    let config = cube2rust::load_ioc(&file_content).expect("load failed");
+    cube2rust::report(&project_dir, &config).expect("report failed");
+
+    let table = fs::read_to_string(project_dir.join("clock_tree.txt")).expect("read failed");
+    let dot = fs::read_to_string(project_dir.join("clock_tree.dot")).expect("read failed");
+    let svg = fs::read_to_string(project_dir.join("clock_tree.svg")).expect("read failed");
+
+    assert!(table.contains("PLLCLK      | PLL_INPUT | PLLMUL x6 | 48 MHz"));
+    assert!(dot.contains("\"PLL_INPUT\" -> \"PLLCLK\" [label=\"PLLMUL x6\"];"));
+    assert!(dot.contains("\"HCLK\" [label=\"HCLK\\n48 MHz\"];"));
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.contains(">PLLMUL x6</text>"));
+}
+
+/// Speeds, pulls of outputs and initial levels come up as CubeMX configures them
+#[test]
+fn test_gpio_electrical_configuration() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PA5.GPIO_Speed=GPIO_SPEED_FREQ_HIGH\nPB12.PinState=GPIO_PIN_RESET\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("pb13.into_push_pull_output(cs)"));
+    assert!(main_rs.contains("out_1.set_high().ok();"));
+    assert!(main_rs.contains("gpio_pb12.set_low().ok();"));
+    assert!(main_rs.contains("pb14.into_open_drain_output(cs)"));
+    assert!(main_rs
+        .contains("(*stm32::GPIOA::ptr()).ospeedr.modify(|_, w| w.ospeedr5().high_speed());"));
+    assert!(main_rs
+        .contains("(*stm32::GPIOB::ptr()).ospeedr.modify(|_, w| w.ospeedr13().medium_speed());"));
+    assert!(main_rs.contains(
+        "(*stm32::GPIOB::ptr()).pupdr.modify(|_, w| w.pupdr12().pull_up().pupdr14().pull_up());"
+    ));
+}
+
+/// EXTI pins get their line mapping, edges, unmasked vector and a handler owning the pin
+#[test]
+fn test_exti() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PC13.GPIOParameters=GPIO_PuPd,GPIO_Label,GPIO_ModeDefaultEXTI\n\
+           PC13.GPIO_Label=button\n\
+           PC13.GPIO_ModeDefaultEXTI=GPIO_MODE_IT_FALLING\n\
+           PC13.GPIO_PuPd=GPIO_PULLUP\n\
+           PC13.Signal=GPXTI13\n\
+           SH.GPXTI13.0=GPIO_EXTI13\n\
+           SH.GPXTI13.ConfNb=1\n\
+           PA0.GPIO_ModeDefaultEXTI=GPIO_MODE_EVT_RISING_FALLING\n\
+           PA0.Signal=GPXTI0\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs
+        .contains("let button = cortex_m::interrupt::free(|cs| pc13.into_pull_up_input(cs));"));
+    assert!(main_rs.contains("syscfg.exticr1.modify(|_, w| w.exti0().bits(0));"));
+    assert!(main_rs.contains("syscfg.exticr4.modify(|_, w| w.exti13().bits(2));"));
+    assert!(main_rs.contains("exti.rtsr.modify(|_, w| w.tr0().set_bit());"));
+    assert!(main_rs.contains("exti.ftsr.modify(|_, w| w.tr0().set_bit().tr13().set_bit());"));
+    assert!(main_rs.contains("exti.imr.modify(|_, w| w.mr13().set_bit());"));
+    assert!(main_rs.contains("exti.emr.modify(|_, w| w.mr0().set_bit());"));
+    assert!(main_rs.contains(
+        "static BUTTON: Mutex<RefCell<Option<hal::gpio::gpioc::PC13<hal::gpio::Input<hal::gpio::PullUp>>>>> ="
+    ));
+    assert!(main_rs.contains("stm32::NVIC::unmask(interrupt::EXTI4_15);"));
+    assert!(main_rs.contains(
+        "#[interrupt]\n#[allow(unused_variables)]\nfn EXTI4_15() {\n    \
+         cortex_m::interrupt::free(|cs| {\n        \
+         let exti = unsafe { &*stm32::EXTI::ptr() };\n        \
+         if exti.pr.read().pr13().bit_is_set() {\n            \
+         // writing 1 clears the pending bit\n            \
+         exti.pr.write(|w| w.pr13().set_bit());\n            \
+         if let Some(button) = BUTTON.borrow(cs).borrow_mut().as_mut() {\n                \
+         // handle the interrupt of button here\n            \
+         }\n        \
+         }\n    \
+         });\n}"
+    ));
+    assert!(!main_rs.contains("interrupt::EXTI0_1"));
+
+    // the event line only needs its pin mode
+    assert!(main_rs.contains("\n    cortex_m::interrupt::free(|cs| pa0.into_floating_input(cs));"));
+    assert!(!main_rs.contains("let exti_pa0"));
+
+    // the lines are shared by all ports
+    let file_content = file_content + "PA13.Signal=GPXTI13\n";
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("GPXTI13 is assigned to more than one pin: PA13, PC13"));
+}
+
+/// Labelled and peripheral pins get a type alias matching their conversion,
+/// labels that had to be changed are kept as a comment
+#[test]
+fn test_pin_aliases() {
+    let file_content =
+        fs::read_to_string(IOC_FILE).expect("read failed") + "PB14.GPIO_Label=LED Red\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("pub mod pins {"));
+    assert!(main_rs.contains("pub type Out1 = gpiob::PB13<Output<PushPull>>;"));
+    assert!(main_rs.contains("pub type In1 = gpioa::PA12<Input<PullUp>>;"));
+    assert!(main_rs.contains("pub type Usart1Tx = gpioa::PA9<Alternate<AF1>>;"));
+    assert!(!main_rs.contains("GpioPb12"));
+    assert!(main_rs.contains("    // LED Red\n    let mut led_red = "));
+    assert!(
+        main_rs.contains("    /// LED Red\n    pub type LedRed = gpiob::PB14<Output<OpenDrain>>;")
+    );
+    // out_1 is the identifier already
+    assert!(!main_rs.contains("/// out_1"));
+}
+
+/// Labels become valid identifiers, names used twice are rejected
+#[test]
+fn test_label_sanitization() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PB13.GPIO_Label=LED Red\nPB14.GPIO_Label=type\nPA12.GPIO_Label=3V3_EN\nPB12.GPIO_Label=[SPI] CS\n";
+
+    let config = cube2rust::load_ioc(&file_content).expect("load failed");
+    let names: Vec<String> = config.gpios.iter().map(|gpio| gpio.get_name()).collect();
+
+    assert!(names.contains(&"led_red".to_string()));
+    assert!(names.contains(&"type_".to_string()));
+    assert!(names.contains(&"pin_3v3_en".to_string()));
+    assert!(names.contains(&"spi_cs".to_string()));
+    assert!(names.contains(&"gpio_pa13".to_string()));
+
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PB13.GPIO_Label=LED\nPB14.GPIO_Label=led\nPA12.GPIO_Label=spi1\n";
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(
+        message.contains("PB14 (GPIO_Label=led) and PB13 (GPIO_Label=LED) both use the name led")
+    );
+    assert!(
+        message.contains("PA12 (GPIO_Label=spi1) and the SPI1 peripheral both use the name spi1")
+    );
+}
+
+/// Numbered labels of the same mode become a group, ordered by their number
+#[test]
+fn test_pin_groups() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PB12.GPIO_Label=LED_0\nPB13.GPIO_Label=LED_1\nPB15.GPIO_Label=LED_2\nPB15.Signal=GPIO_Output\n\
+           PA0.GPIO_Label=DIP_0\nPA0.Signal=GPIO_Input\nPA1.GPIO_Label=DIP_1\nPA1.Signal=GPIO_Output\n";
+
+    let config = cube2rust::load_ioc(&file_content).expect("load failed");
+
+    // different modes aren't grouped
+    assert_eq!(config.pin_groups.len(), 1);
+    let group = &config.pin_groups[0];
+    let registers: Vec<&str> = group
+        .gpios
+        .iter()
+        .map(|&index| config.gpios[index].register.as_str())
+        .collect();
+    assert_eq!(group.name, "leds");
+    assert_eq!(registers, ["pb12", "pb13", "pb15"]);
+
+    let main_rs = cube2rust::generate_main(&config).expect("generate failed");
+
+    assert!(main_rs.contains("use hal::gpio::{Output, Pin, PushPull};"));
+    assert!(main_rs.contains(
+        "let mut leds: [Pin<Output<PushPull>>; 3] = [\n        \
+         led_0.downgrade().downgrade(),\n        \
+         led_1.downgrade().downgrade(),\n        \
+         led_2.downgrade().downgrade(),\n    ];"
+    ));
+}
+
+/// With ProjectManager.FreePins the pins the ioc file doesn't use become analog
+#[test]
+fn test_free_pins() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed");
+
+    let main_rs = generate_main(&file_content);
+    assert!(!main_rs.contains("unused pins"));
+
+    let file_content = file_content.replace(
+        "ProjectManager.FreePins=false",
+        "ProjectManager.FreePins=true",
+    );
+
+    let main_rs = generate_main(&file_content);
+
+    // PA0-PA4, PA8, PA11 and PA15 are free, PA13 and PA14 keep SWD
+    assert!(main_rs
+        .contains("(*stm32::GPIOA::ptr()).moder.modify(|r, w| w.bits(r.bits() | 0xc0c303ff));"));
+    // PC13, PC14 and PC15 keep the LSE
+    assert!(main_rs
+        .contains("(*stm32::GPIOC::ptr()).moder.modify(|r, w| w.bits(r.bits() | 0x0c000000));"));
+    assert!(!main_rs.contains("iopaen"));
+}
+
+/// Debug pins in Serial Wire mode aren't touched, reassigned ones are reconfigured after a delay
+#[test]
+fn test_debug_pins() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed");
+
+    // PA13 is SWDIO after reset, whatever the SYS section says
+    let mut config = cube2rust::load_ioc(&file_content).expect("load failed");
+    let pa13 = config.gpios.iter().find(|gpio| gpio.register == "pa13");
+    assert_eq!(pa13.unwrap().get_debug_function(&config), Some("SWDIO"));
+
+    let main_rs = cube2rust::generate_main(&config).expect("generate failed");
+    assert!(!main_rs.contains("cortex_m::asm::delay"));
+    assert!(main_rs.contains(
+        "let mut gpio_pa13 = cortex_m::interrupt::free(|cs| pa13.into_floating_input(cs));"
+    ));
+
+    config.delay_debug_pins = true;
+    let main_rs = cube2rust::generate_main(&config).expect("generate failed");
+
+    assert!(main_rs.contains(
+        "    // give a debugger one second to attach before PA13 (SWDIO) is reconfigured\n    \
+         cortex_m::asm::delay(48000000);\n    \
+         let mut gpio_pa13 = cortex_m::interrupt::free(|cs| pa13.into_floating_input(cs));"
+    ));
+
+    let file_content = file_content.replace("PA13.Signal=GPIO_Input", "PA13.Signal=SYS_SWDIO")
+        + "PA14.Signal=SYS_SWCLK\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(!main_rs.contains("pa13"));
+    assert!(!main_rs.contains("pa14"));
+}
+
+/// Mcu.UserConstants end up as typed constants, expressions are skipped
+#[test]
+fn test_user_constants() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "Mcu.UserConstants=BUFFER_SIZE,64;OFFSET,-3;BASE_ADDRESS,0x20000100U;TIMEOUT,1.5f;\
+           NAME,\"board, rev 2\";DOUBLE_SIZE,BUFFER_SIZE*2\n";
+
+    let config = cube2rust::load_ioc(&file_content).expect("load failed");
+    let constants: Vec<(&str, &str, &str)> = config
+        .user_constants
+        .iter()
+        .map(|constant| {
+            let value = &constant.value;
+            (constant.name.as_str(), value.rust_type(), value.literal())
+        })
+        .collect();
+
+    assert_eq!(
+        constants,
+        [
+            ("BUFFER_SIZE", "u32", "64"),
+            ("OFFSET", "i32", "-3"),
+            ("BASE_ADDRESS", "u32", "0x20000100"),
+            ("TIMEOUT", "f32", "1.5"),
+            ("NAME", "&str", "\"board, rev 2\""),
+        ]
+    );
+
+    let main_rs = cube2rust::generate_main(&config).expect("generate failed");
+
+    assert!(main_rs.contains("pub mod user_constants {"));
+    assert!(main_rs.contains("pub const BUFFER_SIZE: u32 = 64;"));
+    assert!(main_rs.contains("pub const NAME: &str = \"board, rev 2\";"));
+}
+
+/// ADC channels are collected in AdcChannels, ordered by rank, with a read per channel
+#[test]
+fn test_adc() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "PA1.GPIOParameters=GPIO_Label\n\
+           PA1.GPIO_Label=Pot\n\
+           PA1.Signal=ADC_IN1\n\
+           PA2.Signal=ADC_IN2\n\
+           ADC.Channel-0\\#ChannelRegularConversion=ADC_CHANNEL_TEMPSENSOR\n\
+           ADC.Rank-0\\#ChannelRegularConversion=3\n\
+           ADC.Channel-1\\#ChannelRegularConversion=ADC_CHANNEL_1\n\
+           ADC.Rank-1\\#ChannelRegularConversion=1\n\
+           ADC.Channel-2\\#ChannelRegularConversion=ADC_CHANNEL_2\n\
+           ADC.Rank-2\\#ChannelRegularConversion=2\n\
+           ADC.SamplingTimeCommon=ADC_SAMPLETIME_71CYCLES_5\n\
+           ADC.Resolution=ADC_RESOLUTION_10B\n";
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("let pot = cortex_m::interrupt::free(|cs| pa1.into_analog(cs));"));
+    assert!(main_rs.contains("let mut adc = Adc::new(p.ADC, &mut rcc);"));
+    assert!(main_rs.contains("adc.set_sample_time(AdcSampleTime::T_71);"));
+    assert!(main_rs.contains("adc.set_precision(AdcPrecision::B_10);"));
+    assert!(main_rs.contains("adc.set_align(AdcAlign::Right);"));
+    assert!(main_rs.contains(
+        "let mut adc_channels = AdcChannels {\n        pot,\n        adc_in2,\n        temperature: VTemp::new(),\n    };"
+    ));
+    assert!(main_rs.contains("adc_channels.temperature.enable(&mut adc);"));
+    assert!(main_rs.contains("pub pot: hal::gpio::gpioa::PA1<hal::gpio::Analog>,"));
+    assert!(main_rs.contains("pub temperature: hal::adc::VTemp,"));
+    assert!(main_rs.contains("pub fn read_adc_in2(&mut self, adc: &mut hal::adc::Adc) -> u16 {"));
+    assert!(main_rs.contains("adc.read(&mut self.pot).unwrap()"));
+
+    // every converted input channel needs its pin
+    let file_content = file_content + "ADC.Channel-3\\#ChannelRegularConversion=ADC_CHANNEL_3\n";
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("ADC_CHANNEL_3 is converted but no pin has the ADC_IN3 signal"));
+}
+
+/// Conversion modes the HAL can't do and channels the MCU doesn't have are rejected
+#[test]
+fn test_adc_validation() {
+    let file_content = fs::read_to_string(IOC_FILE).expect("read failed")
+        + "ADC.Channel-0\\#ChannelRegularConversion=ADC_CHANNEL_VBAT\n\
+           ADC.Rank-0\\#ChannelRegularConversion=1\n";
+    cube2rust::load_ioc(&file_content).expect("load failed");
+
+    let continuous = file_content.clone() + "ADC.ContinuousConvMode=ENABLE\n";
+    let error = cube2rust::load_ioc(&continuous).unwrap_err();
+    assert!(format!("{:?}", error).contains("ADC.ContinuousConvMode=ENABLE isn't supported"));
+
+    let backward = file_content.clone() + "ADC.ScanConvMode=ADC_SCAN_DIRECTION_BACKWARD\n";
+    let error = cube2rust::load_ioc(&backward).unwrap_err();
+    assert!(format!("{:?}", error)
+        .contains("ADC.ScanConvMode=ADC_SCAN_DIRECTION_BACKWARD isn't supported"));
+
+    // the F030 has no VBAT pin
+    let f030 = file_content
+        .replace("Mcu.Name=STM32F042C(4-6)Tx", "Mcu.Name=STM32F030C6Tx")
+        .replace("Mcu.UserName=STM32F042C6Tx", "Mcu.UserName=STM32F030C6Tx");
+    let error = cube2rust::load_ioc(&f030).unwrap_err();
+    assert!(format!("{:?}", error).contains("STM32F030C6Tx has no ADC_CHANNEL_VBAT"));
+}
+
+/// DAC outputs keep their pin names, trigger and wave generation are set in the registers
+#[test]
+fn test_dac() {
+    let stock = fs::read_to_string(IOC_FILE).expect("read failed");
+
+    // the F071 has both DAC channels, SPI1_SCK moves to PB3 to free PA5
+    // and I2C1 to PB8 and PB9, PB10 and PB11 are I2C2 there
+    let dac_lines = "PA4.GPIOParameters=GPIO_Label\n\
+                     PA4.GPIO_Label=Wave\n\
+                     PA4.Signal=COMP_DAC1_group\n\
+                     PA5.Signal=DAC_OUT2\n\
+                     DAC.DAC_Trigger-DAC_OUT1=DAC_TRIGGER_T6_TRGO\n\
+                     DAC.DAC_WaveGeneration-DAC_OUT1=DAC_WAVEGENERATION_TRIANGLE\n\
+                     DAC.DAC_LFSRUnmask_TriangleAmplitude-DAC_OUT1=DAC_TRIANGLEAMPLITUDE_1023\n\
+                     DAC.DAC_OutputBuffer-DAC_OUT2=DAC_OUTPUTBUFFER_DISABLE\n";
+    let file_content = stock
+        .replace("Mcu.Name=STM32F042C(4-6)Tx", "Mcu.Name=STM32F071RBTx")
+        .replace("Mcu.UserName=STM32F042C6Tx", "Mcu.UserName=STM32F071RBTx")
+        .replace("Mcu.Package=LQFP48", "Mcu.Package=LQFP64")
+        .replace("Mcu.Pin3=PA5", "Mcu.Pin3=PB3")
+        .replace(
+            "PA5.Mode=Full_Duplex_Master\nPA5.Signal=SPI1_SCK",
+            "PB3.Mode=Full_Duplex_Master\nPB3.Signal=SPI1_SCK",
+        )
+        .replace("PB10", "PB8")
+        .replace("PB11", "PB9")
+        + dac_lines;
+
+    let main_rs = generate_main(&file_content);
+
+    assert!(main_rs.contains("use hal::dac::{dac, DacPin};"));
+    assert!(main_rs.contains("let wave = cortex_m::interrupt::free(|cs| pa4.into_analog(cs));"));
+    assert!(
+        main_rs.contains("let (mut wave, mut dac_out2) = dac(p.DAC, (wave, dac_out2), &mut rcc);")
+    );
+    assert!(main_rs.contains(
+        "dac.cr.modify(|_, w| w.ten1().set_bit().tsel1().bits(0b000).wave1().bits(0b10).mamp1().bits(9).boff2().set_bit());"
+    ));
+    assert!(main_rs.contains("wave.enable();"));
+    assert!(main_rs.contains("dac_out2.enable();"));
+
+    let config = cube2rust::load_ioc(&file_content).expect("load failed");
+    let amplitudes: Vec<Option<u8>> = config
+        .dac
+        .unwrap()
+        .channels
+        .iter()
+        .map(|channel| channel.amplitude)
+        .collect();
+    assert_eq!(amplitudes, [Some(9), None]);
+
+    let noise = file_content
+        .replace("DAC_WAVEGENERATION_TRIANGLE", "DAC_WAVEGENERATION_NOISE")
+        .replace("DAC_TRIANGLEAMPLITUDE_1023", "DAC_LFSRUNMASK_BITS3_0");
+    let config = cube2rust::load_ioc(&noise).expect("load failed");
+    assert_eq!(config.dac.unwrap().channels[0].amplitude, Some(3));
+
+    // 2^(n + 1) - 1 only
+    let invalid = file_content.replace("DAC_TRIANGLEAMPLITUDE_1023", "DAC_TRIANGLEAMPLITUDE_1000");
+    let error = cube2rust::load_ioc(&invalid).unwrap_err();
+    assert!(format!("{:?}", error).contains("invalid DAC amplitude DAC_TRIANGLEAMPLITUDE_1000"));
+
+    // the F042 has no DAC, the F051 no second channel
+    let error = cube2rust::load_ioc(&(stock.replace("PA5", "PB3") + dac_lines)).unwrap_err();
+    assert!(format!("{:?}", error).contains("STM32F042C6Tx has no DAC"));
+
+    let f051 = file_content
+        .replace("Mcu.Name=STM32F071RBTx", "Mcu.Name=STM32F051R(4-6-8)Tx")
+        .replace("Mcu.UserName=STM32F071RBTx", "Mcu.UserName=STM32F051R8Tx");
+    let error = cube2rust::load_ioc(&f051).unwrap_err();
+    assert!(format!("{:?}", error).contains("STM32F051R8Tx has no DAC_OUT2"));
+
+    // the waveform only advances on a trigger
+    let file_content = file_content.replace("DAC_TRIGGER_T6_TRGO", "DAC_TRIGGER_NONE");
+    let error = cube2rust::load_ioc(&file_content).unwrap_err();
+    let message = format!("{:?}", error);
+
+    assert!(message.contains("DAC_OUT1 uses DAC_WAVEGENERATION_TRIANGLE without a trigger"));
+}